@@ -56,9 +56,9 @@ use std::sync::{Arc, Mutex};
 use hyper;
 use std::io::Read;
 use serde_json;
-use hyper::{Client, Request, Body, Method};
+use serde_json::Value;
+use hyper::{Request, Body, Method};
 use hyper::HeaderMap;
-use hyper::client::HttpConnector;
 use hyper::header::{AUTHORIZATION, USER_AGENT, CONTENT_TYPE, HeaderName};
 use futures::{AsyncReadExt, SinkExt};
 use crate::errors::APIError;
@@ -66,20 +66,20 @@ use crate::responses::auth::TokenResponseData;
 use hyper::http::request::Builder;
 use std::iter::Map;
 use std::collections::HashMap;
-use hyper_tls::HttpsConnector;
+use crate::client::transport::{self, HttpClient};
 use std::time::{SystemTime, UNIX_EPOCH};
 use futures::future::ok;
 
 /// Trait for any method of authenticating with the Reddit API.
 pub trait Authenticator {
     /// Logs in and fetches relevant tokens.
-    fn login(&mut self, client: &Client<HttpsConnector<HttpConnector>>, user_agent: &str) -> Result<(), APIError>;
+    fn login(&mut self, client: &HttpClient, user_agent: &str) -> Result<(), APIError>;
     /// Called if a token expiration error occurs.
-    fn refresh_token(&mut self, client: &Client<HttpsConnector<HttpConnector>>, user_agent: &str) -> Result<(), APIError> {
+    fn refresh_token(&mut self, client: &HttpClient, user_agent: &str) -> Result<(), APIError> {
         self.login(client, user_agent)
     }
     /// Logs out and invalidates tokens if applicable.
-    fn logout(&mut self, client: &Client<HttpsConnector<HttpConnector>>, user_agent: &str) -> Result<(), APIError>;
+    fn logout(&mut self, client: &HttpClient, user_agent: &str) -> Result<(), APIError>;
     /// A list of OAuth scopes that this `Authenticator` can access. Currently, the result of this
     /// is not used, but the correct scopes should be returned. If all scopes can be accessed,
     /// this is signified by a vec!["*"]. If it is read-only, the result is vec!["read"].
@@ -95,13 +95,13 @@ pub struct AnonymousAuthenticator;
 
 impl Authenticator for AnonymousAuthenticator {
     #[allow(unused_variables)]
-    fn login(&mut self, client: &Client<HttpsConnector<HttpConnector>>, user_agent: &str) -> Result<(), APIError> {
+    fn login(&mut self, client: &HttpClient, user_agent: &str) -> Result<(), APIError> {
         // Don't log in, because we're anonymous!
         Ok(())
     }
 
     #[allow(unused_variables)]
-    fn logout(&mut self, client: &Client<HttpsConnector<HttpConnector>>, user_agent: &str) -> Result<(), APIError> {
+    fn logout(&mut self, client: &HttpClient, user_agent: &str) -> Result<(), APIError> {
         // Can't log out if we're not logged in.
         Ok(())
     }
@@ -144,7 +144,7 @@ pub struct PasswordAuthenticator {
 }
 
 impl Authenticator for PasswordAuthenticator {
-    fn login(&mut self, client: &Client<HttpsConnector<HttpConnector>>, user_agent: &str) -> Result<(), APIError> {
+    fn login(&mut self, client: &HttpClient, user_agent: &str) -> Result<(), APIError> {
         let url = "https://www.reddit.com/api/v1/access_token";
         let body = format!("grant_type=password&username={}&password={}",
                            &self.username,
@@ -154,40 +154,31 @@ impl Authenticator for PasswordAuthenticator {
             .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
             .header(USER_AGENT, user_agent)
             .body(Body::from(body));
-        if request.is_err() {
-            println!("{}", request.err().unwrap().to_string());
-            return Err(APIError::ExhaustedListing);
-        }
-        let request = request.unwrap();
+        // The request can only be invalid if a header is, e.g. a user agent with a line break.
+        let request = request.map_err(|err| APIError::AuthenticationFailed(err.to_string()))?;
 
         let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
-        let mut result = runtime.block_on(client.request(request));
-        if result.is_err() {
-            println!("{}", result.err().unwrap().to_string());
-            return Err(APIError::ExhaustedListing);
-        }
-        let result = result.unwrap();
+        let result = runtime.block_on(transport::send(client, request))?;
         if result.status() != hyper::StatusCode::OK {
             Err(APIError::HTTPError(result.status()))
         } else {
-            let value = runtime.block_on(hyper::body::to_bytes(result.into_body()));
-
-            let value = String::from_utf8(value.unwrap().to_vec());
-            let string = value.unwrap();
-            let result1 = serde_json::from_str(&string);
-            if result1.is_ok() {
-                let token_response: TokenResponseData = result1.unwrap();
-                self.access_token = Some(token_response.access_token);
-                let x = (token_response.expires_in * 1000);
-                let x1 = (x as u128) + SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
-                self.expire_time = Some(x1);
-                return Ok(());
+            let body = runtime.block_on(hyper::body::to_bytes(result.into_body()))?;
+            // Wrong credentials are reported with a HTTP 200 response such as
+            // `{"error": "invalid_grant"}`.
+            let value: Value = serde_json::from_slice(&body)?;
+            if let Some(error) = value["error"].as_str() {
+                return Err(APIError::AuthenticationFailed(error.to_owned()));
             }
-            return Err(APIError::ExhaustedListing);
+            let token_response: TokenResponseData = serde_json::from_value(value)?;
+            self.access_token = Some(token_response.access_token);
+            let x = (token_response.expires_in * 1000);
+            let x1 = (x as u128) + SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+            self.expire_time = Some(x1);
+            Ok(())
         }
     }
 
-    fn logout(&mut self, client: &Client<HttpsConnector<HttpConnector>>, user_agent: &str) -> Result<(), APIError> {
+    fn logout(&mut self, client: &HttpClient, user_agent: &str) -> Result<(), APIError> {
         let url = "https://www.reddit.com/api/v1/revoke_token";
         let body = format!("token={}", &self.access_token.to_owned().unwrap());
         let request = Request::builder().method(Method::POST).uri(url)
@@ -199,7 +190,7 @@ impl Authenticator for PasswordAuthenticator {
 
         let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
 
-        let res = runtime.block_on(client.request(request.unwrap())).unwrap();
+        let res = runtime.block_on(transport::send(client, request.unwrap()))?;
 
         if !res.status().is_success() {
             Err(APIError::HTTPError(res.status()))
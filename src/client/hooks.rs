@@ -0,0 +1,39 @@
+//! Callbacks that are invoked around every request sent by a `RedditClient`, so that requests can
+//! be logged or measured (e.g. with `tracing` or a metrics library) without `new_rawr` depending
+//! on those libraries. See `RedditClient.set_on_request()` and `RedditClient.set_on_response()`.
+//!
+//! Requests sent by an authenticator to obtain or revoke tokens are not reported.
+
+use std::time::Duration;
+
+use hyper::{Method, StatusCode};
+
+/// A callback invoked before a request is sent.
+pub type RequestHook = Box<dyn Fn(&RequestInfo) + Send + Sync>;
+
+/// A callback invoked when the response to a request has been received.
+pub type ResponseHook = Box<dyn Fn(&ResponseInfo) + Send + Sync>;
+
+/// Describes a request that is about to be sent.
+#[derive(Debug, Clone)]
+pub struct RequestInfo {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The full URL of the request, including the query string.
+    pub url: String,
+    /// True if the request is sent to the OAuth API (`oauth.reddit.com`).
+    pub oauth: bool,
+}
+
+/// Describes the response to a request. This is not reported if the request could not be sent
+/// at all (e.g. because of a network error).
+#[derive(Debug, Clone)]
+pub struct ResponseInfo {
+    /// The request that this is the response to.
+    pub request: RequestInfo,
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+    /// The time between sending the request and receiving the response headers. With the
+    /// `reqwest` feature, this includes reading the response body.
+    pub elapsed: Duration,
+}
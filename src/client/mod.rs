@@ -27,61 +27,101 @@
 //! let client = RedditClient::new(agent, AnonymousAuthenticator::new());
 //! ```
 
+pub mod hooks;
+pub mod transport;
+
 use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
 use std::error::Error;
 use std::io::Read;
 use std::panic::resume_unwind;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Instant;
 
 use futures::AsyncReadExt;
-use hyper::{Body, Method, Request, StatusCode};
-use hyper::client::{Client, HttpConnector};
-use hyper::header::USER_AGENT;
+use hyper::{Body, Method, Request, Response, StatusCode};
+use hyper::header::{CONTENT_TYPE, USER_AGENT};
 use hyper::http::request::Builder;
 use hyper::Uri;
-use hyper_tls::HttpsConnector;
 use serde::Deserialize;
-use serde_json::from_str;
+use serde_json::{from_str, Value};
 
 use crate::auth::Authenticator;
-use crate::errors::APIError;
-use crate::structures::messages::MessageInterface;
-use crate::structures::submission::LazySubmission;
+use crate::client::hooks::{RequestHook, RequestInfo, ResponseHook, ResponseInfo};
+use crate::client::transport::HttpClient;
+use crate::errors::{APIError, RedditError};
+use crate::options::{AccountPreferenceUpdate, LinkPost, ListingOptions, SearchOptions, SearchResultType,
+                     SelfPost};
+use crate::responses::listing::{self, CommentResponse, UserList};
+use crate::responses::user::{AccountPreferences, KarmaEntry, KarmaList};
+use crate::structures::fullname::Fullname;
+use crate::structures::listing::SubredditListing;
+use crate::structures::messages::{MessageInterface, MessageListing};
+use crate::structures::submission::{LazySubmission, Submission};
 use crate::structures::subreddit::Subreddit;
-use crate::structures::user::User;
+use crate::structures::user::{User, UserListing};
 
 /// A client to connect to Reddit. See the module-level documentation for examples.
 pub struct RedditClient {
-    /// The internal HTTP client. You should not need to manually use this. If you do, file an
-    /// issue saying why the API does not support your use-case, and we'll try to add it.
-    pub client: Client<HttpsConnector<HttpConnector>>,
+    // The internal HTTP client. If you need to send requests that the API does not support,
+    // file an issue saying why, and we'll try to add it.
+    client: HttpClient,
     user_agent: String,
     authenticator: Arc<Mutex<Box<dyn Authenticator + Send>>>,
     auto_logout: bool,
+    raw_json: bool,
+    on_request: Option<RequestHook>,
+    on_response: Option<ResponseHook>,
 }
 
 
 impl RedditClient {
     /// Creates an instance of the `RedditClient` using the provided user agent.
+    /// # Panics
+    /// Panics if the authenticator cannot log in. Use `RedditClient::try_new()` to handle this
+    /// instead.
     pub fn new(user_agent: &str,
                authenticator: Arc<Mutex<Box<dyn Authenticator + Send>>>)
                -> RedditClient {
-        // Connection pooling is problematic if there are pauses/sleeps in the program, so we
-        // choose to disable it by using a non-pooling connector.
-        let https = HttpsConnector::new();
-        let client = Client::builder().build::<_, hyper::Body>(https);
-        let this = RedditClient {
-            client: client,
+        RedditClient::try_new(user_agent, authenticator)
+            .expect("Authentication failed. Did you use the correct username/password?")
+    }
+
+    /// Creates an instance of the `RedditClient` using the provided user agent, returning the
+    /// error if the authenticator cannot log in (e.g. `APIError::AuthenticationFailed` if the
+    /// password is wrong).
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// let authenticator = PasswordAuthenticator::new("a", "b", "c", "d");
+    /// match RedditClient::try_new("new_rawr", authenticator) {
+    ///     Ok(client) => println!("Logged in"),
+    ///     Err(err) => println!("Could not log in: {}", err),
+    /// }
+    /// ```
+    pub fn try_new(user_agent: &str,
+                   authenticator: Arc<Mutex<Box<dyn Authenticator + Send>>>)
+                   -> Result<RedditClient, APIError> {
+        let client = transport::new_client();
+        let mut this = RedditClient {
+            client,
             user_agent: user_agent.to_owned(),
-            authenticator: authenticator,
-            auto_logout: true,
+            authenticator,
+            // There is nothing to log out of until the login has succeeded.
+            auto_logout: false,
+            raw_json: true,
+            on_request: None,
+            on_response: None,
         };
 
-        this.get_authenticator()
-            .login(&this.client, &this.user_agent)
-            .expect("Authentication failed. Did you use the correct username/password?");
-        this
+        this.get_authenticator().login(&this.client, &this.user_agent)?;
+        this.auto_logout = true;
+        Ok(this)
     }
 
     /// Disables the automatic logout that occurs when the client drops out of scope.
@@ -101,6 +141,120 @@ impl RedditClient {
         self.auto_logout = val;
     }
 
+    /// Changes the user agent sent with all subsequent requests, e.g. to run multiple logical bots
+    /// from one client. See the module-level documentation for the recommended format.
+    /// # Examples
+    /// ```
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let mut client = RedditClient::new("linux:new_rawr:v0.0.1", AnonymousAuthenticator::new());
+    /// client.set_user_agent("linux:new_rawr_second_bot:v0.0.1");
+    /// assert_eq!(client.user_agent(), "linux:new_rawr_second_bot:v0.0.1");
+    /// ```
+    pub fn set_user_agent(&mut self, user_agent: &str) {
+        self.user_agent = user_agent.to_owned();
+    }
+
+    /// Gets the user agent that is currently sent with every request.
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    /// Sets whether `raw_json=1` is added to every request (the default is `true`). With
+    /// `raw_json`, Reddit sends text such as titles and comment bodies as-is; without it, `<`,
+    /// `>` and `&` are escaped as HTML entities (e.g. `&amp;`), like the legacy API did.
+    /// # Examples
+    /// ```
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let mut client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// assert!(client.raw_json());
+    /// client.set_raw_json(false);
+    /// assert!(!client.raw_json());
+    /// ```
+    pub fn set_raw_json(&mut self, raw_json: bool) {
+        self.raw_json = raw_json;
+    }
+
+    /// Sets a callback that is invoked before each request is sent, with its method, URL and
+    /// whether OAuth is used. This replaces any previous callback. See the `hooks` module.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let mut client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// client.set_on_request(Box::new(|request| println!("{} {}", request.method, request.url)));
+    /// ```
+    pub fn set_on_request(&mut self, hook: RequestHook) {
+        self.on_request = Some(hook);
+    }
+
+    /// Sets a callback that is invoked when the response to a request is received, with its
+    /// status and the time the request took. This replaces any previous callback.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let mut client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// client.set_on_response(Box::new(|response| {
+    ///     println!("{} {} took {:?}", response.status, response.request.url, response.elapsed);
+    /// }));
+    /// ```
+    pub fn set_on_response(&mut self, hook: ResponseHook) {
+        self.on_response = Some(hook);
+    }
+
+    /// Sends a request with the HTTP client, and reports it to the request and response hooks.
+    pub(crate) async fn send(&self, request: Request<Body>) -> Result<Response<Body>, APIError> {
+        if self.on_request.is_none() && self.on_response.is_none() {
+            return transport::send(&self.client, request).await;
+        }
+        let info = RequestInfo {
+            method: request.method().clone(),
+            url: request.uri().to_string(),
+            oauth: request.uri().host() == Some("oauth.reddit.com"),
+        };
+        if let Some(ref hook) = self.on_request {
+            hook(&info);
+        }
+        let start = Instant::now();
+        let response = transport::send(&self.client, request).await?;
+        if let Some(ref hook) = self.on_response {
+            hook(&ResponseInfo {
+                request: info,
+                status: response.status(),
+                elapsed: start.elapsed(),
+            });
+        }
+        Ok(response)
+    }
+
+    /// Gets whether `raw_json=1` is added to every request. See
+    /// `RedditClient.set_raw_json()`.
+    pub fn raw_json(&self) -> bool {
+        self.raw_json
+    }
+
+    /// Adds or removes the `raw_json` parameter of a destination URL according to
+    /// `RedditClient.raw_json()`, so that it is sent exactly once when enabled.
+    fn apply_raw_json(&self, dest: &str) -> String {
+        let (path, query) = match dest.find('?') {
+            Some(index) => (&dest[..index], &dest[index + 1..]),
+            None => (dest, ""),
+        };
+        let mut params = query.split('&')
+            .filter(|param| !param.is_empty() && !param.starts_with("raw_json="))
+            .collect::<Vec<_>>();
+        if self.raw_json {
+            params.push("raw_json=1");
+        }
+        if params.is_empty() {
+            path.to_owned()
+        } else {
+            format!("{}?{}", path, params.join("&"))
+        }
+    }
+
     /// Runs the lambda passed in. Refreshes the access token if it fails due to an HTTP 401
     /// Unauthorized error, then reruns the lambda. If the lambda fails twice, or fails due to
     /// a different error, the error is returned.
@@ -110,8 +264,7 @@ impl RedditClient {
         let res = lambda();
         match res {
             Err(APIError::HTTPError(StatusCode::UNAUTHORIZED)) => {
-                self.get_authenticator().refresh_token(&self.client, &self.user_agent).expect("Authentication failed. Did you use the correct username/password?");
-                ;
+                self.get_authenticator().refresh_token(&self.client, &self.user_agent)?;
                 lambda()
             }
             _ => res,
@@ -130,6 +283,98 @@ impl RedditClient {
         Subreddit::create_new(self, &self.url_escape(name.to_owned()))
     }
 
+    /// Checks whether a subreddit exists and can be viewed, e.g. to validate a subreddit name
+    /// entered by a user. Subreddits that do not exist, are banned or are private give
+    /// `Ok(false)`; other errors (such as network problems) are returned as they are.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// assert!(client.subreddit_exists("learnprogramming").expect("Could not check subreddit"));
+    /// ```
+    pub fn subreddit_exists(&self, name: &str) -> Result<bool, APIError> {
+        match self.subreddit(name).about() {
+            Ok(_) => Ok(true),
+            // Reddit redirects to the search page for some names that do not exist.
+            Err(APIError::HTTPError(StatusCode::NOT_FOUND)) |
+            Err(APIError::HTTPError(StatusCode::FORBIDDEN)) |
+            Err(APIError::HTTPError(StatusCode::FOUND)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Subscribes to all of the specified subreddits, sending one request for every 100
+    /// subreddits instead of one per subreddit like `Subreddit.subscribe()`. If a request fails,
+    /// the remaining subreddits are not subscribed to.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// client.subscribe_subreddits(&["rust", "learnrust", "programming"])
+    ///     .expect("Could not subscribe");
+    /// ```
+    pub fn subscribe_subreddits(&self, names: &[&str]) -> Result<(), APIError> {
+        self.bulk_subscribe("sub", names)
+    }
+
+    /// Unsubscribes from all of the specified subreddits. See
+    /// `RedditClient.subscribe_subreddits()`.
+    pub fn unsubscribe_subreddits(&self, names: &[&str]) -> Result<(), APIError> {
+        self.bulk_subscribe("unsub", names)
+    }
+
+    fn bulk_subscribe(&self, action: &str, names: &[&str]) -> Result<(), APIError> {
+        for chunk in names.chunks(100) {
+            let names = chunk.iter()
+                .map(|name| self.url_escape((*name).to_owned()))
+                .collect::<Vec<_>>();
+            let body = format!("action={}&sr_name={}", action, names.join(","));
+            self.post_success("/api/subscribe", &body, false)?;
+        }
+        Ok(())
+    }
+
+    /// Submits the same text post to each of the specified subreddits, e.g. to announce
+    /// something in several communities. The posts are submitted one after another, as Reddit
+    /// limits how quickly an account can post, and a failure does not stop the remaining
+    /// submissions. Each result is returned together with the name of its subreddit. Flair
+    /// templates belong to a single subreddit, so a post with a flair should not be sent to
+    /// several subreddits at once.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::SelfPost;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let post = SelfPost::new("new_rawr 1.0 released!", "Check it out on crates.io.");
+    /// for (sub, result) in client.submit_to_multiple(&["rust", "learnrust"], &post) {
+    ///     if let Err(err) = result {
+    ///         println!("Could not post to /r/{}: {}", sub, err);
+    ///     }
+    /// }
+    /// ```
+    pub fn submit_to_multiple(&self, subreddits: &[&str], post: &SelfPost)
+                              -> Vec<(String, Result<(), APIError>)> {
+        subreddits.iter()
+            .map(|&name| {
+                (name.to_owned(), self.subreddit(name).submit_text(post.clone()))
+            })
+            .collect()
+    }
+
+    /// Submits the same link post to each of the specified subreddits. See
+    /// `RedditClient.submit_to_multiple()` for details.
+    pub fn submit_link_to_multiple(&self, subreddits: &[&str], post: &LinkPost)
+                                   -> Vec<(String, Result<(), APIError>)> {
+        subreddits.iter()
+            .map(|&name| {
+                (name.to_owned(), self.subreddit(name).submit_link(post.clone()))
+            })
+            .collect()
+    }
+
     /// Gets the specified user in order to get user-related data such as the 'about' page.
     pub fn user(&self, name: &str) -> User {
         User::new(self, &self.url_escape(name.to_owned()))
@@ -155,84 +400,85 @@ impl RedditClient {
         format!("{}{}", stem, dest)
     }
 
-    /// Wrapper around the `get` function of `hyper::client::Client`, which sends a HTTP GET
-    /// request. The correct user agent header is also sent using this function, which is necessary
-    /// to prevent 403 errors.
-    pub fn get(&self, dest: &str, oauth_required: bool) -> Builder {
-        let mut authenticator = self.get_authenticator();
-        let url = self.build_url(dest, oauth_required, &mut authenticator);
-
-        let mut builder = (Builder::new());
-        let mut headers = authenticator.headers();
-        if headers.is_err() {
-            if headers.err().unwrap().to_string().eq("ExpiredToken") {
-                authenticator.login(&self.client, &*self.user_agent).expect("Authentication failed. Did you use the correct username/password?");
-            }
-        }
-        headers = authenticator.headers();
-        for x in headers.unwrap() {
-            builder = builder.header(x.0, x.1);
-        }
-        builder.method(Method::GET).uri(url).header(USER_AGENT, self.user_agent.to_owned())
+    /// Builds a HTTP GET request to the API. The correct user agent and authentication headers
+    /// are also sent using this function, which is necessary to prevent 403 errors. If the
+    /// access token has expired, the authenticator logs in again, and its error is returned if
+    /// that fails.
+    pub fn get(&self, dest: &str, oauth_required: bool) -> Result<Builder, APIError> {
+        self.request_builder(Method::GET, dest, oauth_required)
     }
 
     /// Sends a GET request with the specified parameters, and returns the resulting
     /// deserialized object.
     pub fn get_json(&self, dest: &str, oauth_required: bool) -> Result<String, APIError> {
         self.ensure_authenticated(|| {
-            let request = self.get(dest, oauth_required).body(Body::empty()).unwrap();
+            let request = self.get(dest, oauth_required)?.body(Body::empty()).unwrap();
 
             let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
 
-            let response = runtime.block_on(self.client.request(request)).unwrap();
+            let response = runtime.block_on(self.send(request))?;
             if response.status().is_success() {
-                let value = runtime.block_on(hyper::body::to_bytes(response.into_body()));
-                Ok(String::from_utf8(value.unwrap().to_vec()).unwrap().parse().unwrap())
+                let value = runtime.block_on(hyper::body::to_bytes(response.into_body()))?;
+                Ok(String::from_utf8_lossy(&value).into_owned())
             } else {
                 Err(APIError::HTTPError(response.status()))
             }
         })
     }
 
-    /// Wrapper around the `post` function of `hyper::client::Client`, which sends a HTTP POST
-    /// request. The correct user agent header is also sent using this function, which is necessary
-    /// to prevent 403 errors.
-    pub fn post(&self, dest: &str, oauth_required: bool) -> Builder {
+    /// Builds a HTTP POST request to the API. See `RedditClient.get()`.
+    pub fn post(&self, dest: &str, oauth_required: bool) -> Result<Builder, APIError> {
+        self.request_builder(Method::POST, dest, oauth_required)
+    }
+
+    fn request_builder(&self, method: Method, dest: &str, oauth_required: bool)
+                       -> Result<Builder, APIError> {
         let mut authenticator = self.get_authenticator();
-        let url = self.build_url(dest, oauth_required, &mut authenticator);
-        let mut builder = Request::builder().method(Method::POST).uri(url);
-        let mut headers = authenticator.headers();
-        if headers.is_err() {
-            if headers.err().unwrap().to_string().eq("ExpiredToken") {
-                authenticator.login(&self.client, &*self.user_agent);
-            }
+        let url = self.build_url(&self.apply_raw_json(dest), oauth_required, &mut authenticator);
+        if let Err(APIError::ExpiredToken) = authenticator.headers() {
+            authenticator.login(&self.client, &self.user_agent)?;
         }
-        headers = authenticator.headers();
-        for x in headers.unwrap() {
-            builder = builder.header(x.0, x.1);
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(url)
+            .header(USER_AGENT, self.user_agent.to_owned());
+        for (name, value) in authenticator.headers()? {
+            builder = builder.header(name, value);
         }
-        builder.header(USER_AGENT, self.user_agent.to_owned())
+        Ok(builder)
     }
 
     /// Sends a post request with the specified parameters, and converts the resulting JSON
     /// into a deserialized object.
     pub fn post_json(&self, dest: &str, body: &str, oauth_required: bool) -> Result<String, APIError> {
         self.ensure_authenticated(|| {
-            let request = self.post(dest, oauth_required).body(Body::from(body.to_string())).unwrap();
+            let request = self.post(dest, oauth_required)?.body(Body::from(body.to_string())).unwrap();
 
             let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
 
-            let response = runtime.block_on(self.client.request(request)).unwrap();
+            let response = runtime.block_on(self.send(request))?;
             let status = response.status();
             if status.is_success() {
-                let value = runtime.block_on(hyper::body::to_bytes(response.into_body()));
-                Ok(String::from_utf8(value.unwrap().to_vec()).unwrap().parse().unwrap())
+                let value = runtime.block_on(hyper::body::to_bytes(response.into_body()))?;
+                Ok(String::from_utf8_lossy(&value).into_owned())
             } else {
                 Err(APIError::HTTPError(status))
             }
         })
     }
 
+    /// Sends a post request like `post_json`, and parses the response. If Reddit reports an
+    /// error in the `json.errors` array (which happens even with a HTTP 200 response),
+    /// `APIError::RedditError` is returned. The request body should include `api_type=json`.
+    pub fn post_api(&self, dest: &str, body: &str, oauth_required: bool) -> Result<Value, APIError> {
+        let response = self.post_json(dest, body, oauth_required)?;
+        let response: Value = from_str(&response)?;
+        match RedditError::from_response(&response) {
+            Some(err) => Err(APIError::RedditError(err)),
+            None => Ok(response),
+        }
+    }
+
     /// Sends a post request with the specified parameters, and ensures that the response
     /// has a success header (HTTP 2xx).
     pub fn post_success(&self,
@@ -241,11 +487,158 @@ impl RedditClient {
                         oauth_required: bool)
                         -> Result<(), APIError> {
         self.ensure_authenticated(|| {
-            let request = self.post(dest, oauth_required).body(Body::from(body.to_string())).unwrap();
+            let request = self.post(dest, oauth_required)?.body(Body::from(body.to_string())).unwrap();
 
             let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
 
-            let response = runtime.block_on(self.client.request(request)).unwrap();
+            let response = runtime.block_on(self.send(request))?;
+            if response.status().is_success() {
+                Ok(())
+            } else {
+                Err(APIError::HTTPError(response.status()))
+            }
+        })
+    }
+
+    /// Uploads an image or video to Reddit's media storage, returning the asset ID that can be
+    /// used to reference the media when submitting (e.g. in `Subreddit.submit_gallery()`).
+    pub fn upload_media(&self, data: &[u8], mime_type: &str) -> Result<String, APIError> {
+        let extension = mime_type.split('/').nth(1).unwrap_or("bin");
+        let body = format!("filepath=upload.{}&mimetype={}",
+                           extension,
+                           self.url_escape(mime_type.to_owned()));
+        let lease = self.post_json("/api/media/asset.json", &body, false)?;
+        let lease: Value = from_str(&lease)?;
+        let asset_id = lease["asset"]["asset_id"].as_str();
+        let action = lease["args"]["action"].as_str();
+        let fields = lease["args"]["fields"].as_array();
+        let (asset_id, action, fields) = match (asset_id, action, fields) {
+            (Some(asset_id), Some(action), Some(fields)) => (asset_id, action, fields),
+            _ => {
+                let err = serde::de::Error::custom("media lease is missing the upload details");
+                return Err(APIError::JSONError(err));
+            }
+        };
+
+        // The upload itself goes straight to the storage bucket as a multipart form, and must not
+        // include the Reddit authorization headers.
+        let fields = fields.iter().map(|field| {
+            (field["name"].as_str().unwrap_or(""), field["value"].as_str().unwrap_or(""))
+        });
+        let filename = format!("upload.{}", extension);
+        let (content_type, form) = RedditClient::multipart_body(fields,
+                                                                Some((&filename, data, mime_type)));
+
+        let url = if action.starts_with("//") {
+            format!("https:{}", action)
+        } else {
+            action.to_owned()
+        };
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url)
+            .header(USER_AGENT, self.user_agent.to_owned())
+            .header(CONTENT_TYPE, content_type)
+            .body(Body::from(form))
+            .unwrap();
+        let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
+        let response = runtime.block_on(self.send(request))?;
+        if response.status().is_success() {
+            Ok(asset_id.to_owned())
+        } else {
+            Err(APIError::HTTPError(response.status()))
+        }
+    }
+
+    /// Sends a post request with a `multipart/form-data` body instead of a URL encoded one, as
+    /// needed by endpoints that accept file uploads. The file is given as
+    /// `(filename, data, mime_type)` and is sent in the `file` field, after the other fields.
+    /// # Examples
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let image = std::fs::read("header.png").unwrap();
+    /// let mut fields = HashMap::new();
+    /// fields.insert("img_type", "png");
+    /// fields.insert("upload_type", "header");
+    /// client.post_multipart("/r/new_rawr/api/upload_sr_img", fields,
+    ///                       Some(("header.png", &image, "image/png")), false)
+    ///     .expect("Could not upload image");
+    /// ```
+    pub fn post_multipart(&self,
+                          dest: &str,
+                          fields: HashMap<&str, &str>,
+                          file: Option<(&str, &[u8], &str)>,
+                          oauth_required: bool)
+                          -> Result<String, APIError> {
+        let (content_type, form) = RedditClient::multipart_body(fields.into_iter(), file);
+        self.ensure_authenticated(|| {
+            let request = self.post(dest, oauth_required)?
+                .header(CONTENT_TYPE, content_type.to_owned())
+                .body(Body::from(form.to_owned()))
+                .unwrap();
+            let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
+            let response = runtime.block_on(self.send(request))?;
+            let status = response.status();
+            if status.is_success() {
+                let body = runtime.block_on(hyper::body::to_bytes(response.into_body()))?;
+                Ok(String::from_utf8_lossy(&body).into_owned())
+            } else {
+                Err(APIError::HTTPError(status))
+            }
+        })
+    }
+
+    /// Builds a `multipart/form-data` body from the fields and optional file, returning the
+    /// content type (including the boundary) and the body. A new random boundary is used for
+    /// every body, so it cannot clash with the uploaded data.
+    pub(crate) fn multipart_body<'f, I>(fields: I,
+                                        file: Option<(&str, &[u8], &str)>)
+                                        -> (String, Vec<u8>)
+        where I: Iterator<Item = (&'f str, &'f str)>
+    {
+        let random = || RandomState::new().build_hasher().finish();
+        let boundary = format!("new_rawr_{:016x}{:016x}", random(), random());
+        // Quotes and line breaks would end the parameter early, so they are percent-encoded
+        // like browsers do.
+        let escape = |param: &str| {
+            param.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A")
+        };
+        let mut form = Vec::new();
+        for (name, value) in fields {
+            form.extend(format!("--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                                boundary, escape(name), value).into_bytes());
+        }
+        if let Some((filename, data, mime_type)) = file {
+            form.extend(format!("--{}\r\nContent-Disposition: form-data; name=\"file\"; \
+                                 filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                                boundary, escape(filename), mime_type).into_bytes());
+            form.extend_from_slice(data);
+            form.extend_from_slice(b"\r\n");
+        }
+        form.extend(format!("--{}--\r\n", boundary).into_bytes());
+        (format!("multipart/form-data; boundary={}", boundary), form)
+    }
+
+    /// Sends a HTTP PATCH request with the specified JSON body, and ensures that the response
+    /// has a success header (HTTP 2xx).
+    pub fn patch_success(&self,
+                         dest: &str,
+                         body: &str,
+                         oauth_required: bool)
+                         -> Result<(), APIError> {
+        self.ensure_authenticated(|| {
+            let request = self.post(dest, oauth_required)?
+                .method(Method::PATCH)
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap();
+
+            let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
+
+            let response = runtime.block_on(self.send(request))?;
             if response.status().is_success() {
                 Ok(())
             } else {
@@ -284,18 +677,135 @@ impl RedditClient {
     }
 
     /// Gets a `LazySubmission` object which can be used to access the information/comments of a
-    /// specified post. The **full** name of the item should be used.
+    /// specified post. The **full** name of the item should be used; anything else (including
+    /// the full name of a comment) gives an `APIError::InvalidFullname`.
     /// # Examples
-    /// ```
+    /// ```rust,no_run
     ///
     /// use new_rawr::client::RedditClient;
     /// use new_rawr::auth::AnonymousAuthenticator;
     /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
-    /// let post = client.get_by_id("t3_4uule8").get().expect("Could not get post.");
+    /// let post = client.get_by_id("t3_4uule8").unwrap().get().expect("Could not get post.");
+    /// assert_eq!(post.title(), "[C#] Abstract vs Interface");
+    /// ```
+    pub fn get_by_id(&self, id: &str) -> Result<LazySubmission, APIError> {
+        let id = Fullname::from_str(id)?.expect_kind("t3")?;
+        Ok(LazySubmission::new(self, id.as_str()))
+    }
+
+    /// Fetches the submission that a link points to. Links to a post on any Reddit domain
+    /// (e.g. `https://old.reddit.com/r/rust/comments/4uule8/some_title/`) and short links
+    /// (`https://redd.it/4uule8`) are supported. Any other URL gives an `APIError::InvalidURL`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let url = "https://www.reddit.com/r/learnprogramming/comments/4uule8/c_abstract_vs_interface/";
+    /// let post = client.get_by_url(url).expect("Could not get post.");
     /// assert_eq!(post.title(), "[C#] Abstract vs Interface");
     /// ```
-    pub fn get_by_id(&self, id: &str) -> LazySubmission {
-        LazySubmission::new(self, &self.url_escape(id.to_owned()))
+    pub fn get_by_url(&self, url: &str) -> Result<Submission<'_>, APIError> {
+        let id = RedditClient::post_id_from_url(url)
+            .ok_or_else(|| APIError::InvalidURL(url.to_owned()))?;
+        let path = format!("/comments/{}?limit=0&raw_json=1", id);
+        let result = self.get_json(&path, false)?;
+        let response: CommentResponse = from_str(&result)?;
+        let post = response.into_link().data.children.into_iter().next().ok_or(APIError::ExhaustedListing)?;
+        Ok(Submission::new(self, post.data))
+    }
+
+    /// Finds a post that links to `url`, in any subreddit. Returns `None` if the URL has not
+    /// been submitted yet, which makes this useful for avoiding reposts. Unlike `get_by_url`,
+    /// the URL is the *target* of the post rather than a link to the post itself.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let url = "https://www.rust-lang.org/";
+    /// if let Some(post) = client.submission_by_url(url).expect("Could not search for URL.") {
+    ///     println!("Already posted: {}", post.title());
+    /// }
+    /// ```
+    pub fn submission_by_url(&self, url: &str) -> Result<Option<Submission<'_>>, APIError> {
+        let path = format!("/api/info?url={}&raw_json=1", self.url_escape(url.to_owned()));
+        let result = self.get_json(&path, false)?;
+        let posts: listing::Listing = from_str(&result)?;
+        Ok(posts.data.children.into_iter().next().map(|post| Submission::new(self, post.data)))
+    }
+
+    fn post_id_from_url(url: &str) -> Option<&str> {
+        let url = url.trim();
+        let url = url.split("://").nth(1).unwrap_or(url);
+        let url = url.split(['?', '#']).next()?;
+        let mut parts = url.split('/').filter(|part| !part.is_empty());
+        let host = parts.next()?.to_lowercase();
+        let segments = parts.collect::<Vec<_>>();
+        let id = if host == "redd.it" || host.ends_with(".redd.it") {
+            segments.first()
+        } else if host == "reddit.com" || host.ends_with(".reddit.com") {
+            match segments.as_slice() {
+                ["r", _, "comments", id, ..] | ["comments", id, ..] => Some(id),
+                _ => None,
+            }
+        } else {
+            None
+        }?;
+        if id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            Some(id)
+        } else {
+            None
+        }
+    }
+
+    /// Gets a listing of the most popular subreddits, as shown on /subreddits.
+    pub fn popular_subreddits(&self, opts: ListingOptions) -> Result<SubredditListing<'_>, APIError> {
+        self.subreddit_feed("popular", opts)
+    }
+
+    /// Gets a listing of the newest subreddits. See `RedditClient.popular_subreddits()` for an
+    /// example.
+    pub fn new_subreddits(&self, opts: ListingOptions) -> Result<SubredditListing<'_>, APIError> {
+        self.subreddit_feed("new", opts)
+    }
+
+    /// Searches all of Reddit for subreddits matching the specified query, e.g. by name or
+    /// topic. The `SearchOptions` set the order and time span of the results.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::SearchOptions;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let results = client.search_subreddits("rust", SearchOptions::default())
+    ///     .expect("Search failed");
+    /// for sub in results.take(5) {
+    ///     println!("/r/{}", sub.display_name());
+    /// }
+    /// ```
+    pub fn search_subreddits(&self, query: &str, opts: SearchOptions)
+                             -> Result<SubredditListing<'_>, APIError> {
+        let path = format!("/search?q={}&{}&{}{}&",
+                           self.url_escape(query.to_owned()),
+                           SearchResultType::Subreddit,
+                           opts.sort,
+                           opts.time);
+        self.subreddit_listing(&path, opts.listing)
+    }
+
+    fn subreddit_feed(&self, ty: &str, opts: ListingOptions) -> Result<SubredditListing<'_>, APIError> {
+        self.subreddit_listing(&format!("/subreddits/{}?", ty), opts)
+    }
+
+    fn subreddit_listing(&self, path: &str, opts: ListingOptions)
+                         -> Result<SubredditListing<'_>, APIError> {
+        // As in `Subreddit` feeds, the anchor is left out of the stem so pagination can change it.
+        let url = format!("{}limit={}&raw_json=1", path, opts.batch);
+        let full_url = format!("{}&{}", url, opts.anchor);
+        let result = self.get_json(&full_url, false)?;
+        let result: listing::SubredditListing = from_str(&result)?;
+        Ok(SubredditListing::new(self, url, result.data))
     }
 
     /// Gets a `MessageInterface` object which allows access to the message listings (e.g. `inbox`,
@@ -315,15 +825,283 @@ impl RedditClient {
     pub fn messages(&self) -> MessageInterface {
         MessageInterface::new(self)
     }
+
+    /// Gets the username mentions of the logged-in user. This is a shortcut for
+    /// `client.messages().mentions(opts)`.
+    pub fn mentions(&self, opts: ListingOptions) -> Result<MessageListing<'_>, APIError> {
+        self.messages().mentions(opts)
+    }
+
+    /// Gets the karma of the logged-in user, broken down by subreddit. This requires an OAuth
+    /// authenticator with the `mysubreddits` scope.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// for entry in client.karma_breakdown().expect("Could not fetch karma") {
+    ///     println!("/r/{}: {} link, {} comment", entry.sr, entry.link_karma, entry.comment_karma);
+    /// }
+    /// ```
+    pub fn karma_breakdown(&self) -> Result<Vec<KarmaEntry>, APIError> {
+        let result = self.get_json("/api/v1/me/karma?raw_json=1", true)?;
+        let result: KarmaList = from_str(&result)?;
+        Ok(result.data)
+    }
+
+    /// Gets the friends of the logged-in user. This requires an OAuth authenticator with the
+    /// `read` scope.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// for friend in client.friends().expect("Could not fetch friends") {
+    ///     println!("{}", friend.name);
+    /// }
+    /// ```
+    pub fn friends(&self) -> Result<UserListing<'_>, APIError> {
+        let url = "/api/v1/me/friends?raw_json=1";
+        let result = self.get_json(url, true)?;
+        let result: UserList = from_str(&result)?;
+        Ok(UserListing::new(self, url.to_owned(), result.data))
+    }
+
+    /// Gets the preferences of the logged-in account, such as night mode and NSFW settings. This
+    /// requires an OAuth authenticator with the `account` scope.
+    pub fn account_preferences(&self) -> Result<AccountPreferences, APIError> {
+        let result = self.get_json("/api/v1/me/prefs?raw_json=1", true)?;
+        Ok(from_str(&result)?)
+    }
+
+    /// Changes the preferences of the logged-in account. Only the fields set in the
+    /// `AccountPreferenceUpdate` are changed. This requires an OAuth authenticator with the
+    /// `account` scope.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::AccountPreferenceUpdate;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let update = AccountPreferenceUpdate {
+    ///     over_18: Some(true),
+    ///     ..AccountPreferenceUpdate::default()
+    /// };
+    /// client.update_preferences(update).expect("Could not update preferences");
+    /// ```
+    pub fn update_preferences(&self, prefs: AccountPreferenceUpdate) -> Result<(), APIError> {
+        let body = serde_json::to_string(&prefs)?;
+        self.patch_success("/api/v1/me/prefs", &body, true)
+    }
+
+    /// Runs the function on every item, with up to `concurrency` requests in flight at once, and
+    /// returns the results in the same order as the items. Failures do not stop the batch, so
+    /// check each result to find out which items failed. If the function panics for an item,
+    /// its result is `APIError::RequestPanicked`.
+    ///
+    /// Keep the concurrency low (e.g. 4) - Reddit limits OAuth clients to 60 requests per minute
+    /// and other clients to 30, and the batch does not wait between requests.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let ids = ["t3_4uule8", "t1_d5t5q3c"];
+    /// let results = client.batch(&ids, 2, |id| {
+    ///     client.post_success("/api/save", &format!("id={}", id), false)
+    /// });
+    /// ```
+    pub fn batch<T, R, F>(&self, items: &[T], concurrency: usize, f: F) -> Vec<Result<R, APIError>>
+        where T: Sync,
+              R: Send,
+              F: Fn(&T) -> Result<R, APIError> + Sync
+    {
+        let mut results = Vec::with_capacity(items.len());
+        for chunk in items.chunks(concurrency.max(1)) {
+            let f = &f;
+            thread::scope(|scope| {
+                let handles = chunk.iter()
+                    .map(|item| scope.spawn(move || f(item)))
+                    .collect::<Vec<_>>();
+                for handle in handles {
+                    results.push(handle.join().unwrap_or(Err(APIError::RequestPanicked)));
+                }
+            });
+        }
+        results
+    }
+
+    /// Fetches the submissions with the specified full names (e.g. `t3_4uule8`). Reddit returns
+    /// up to 100 posts per request, so the IDs are split into chunks of 100 and up to
+    /// `concurrency` chunks are fetched at once (see `RedditClient.batch()`). Posts that no
+    /// longer exist are left out. The first invalid ID or failed request is returned as an
+    /// error; use `RedditClient.get_all_by_ids_tolerant()` to skip failures instead.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let posts = client.get_all_by_ids(&["t3_4uule8", "t3_4uvnk8"], 2).expect("Request failed");
+    /// for post in posts {
+    ///     println!("{}", post.title());
+    /// }
+    /// ```
+    pub fn get_all_by_ids(&self, ids: &[&str], concurrency: usize)
+                          -> Result<Vec<Submission<'_>>, APIError> {
+        for id in ids {
+            Fullname::from_str(id)?.expect_kind("t3")?;
+        }
+        let mut posts = Vec::with_capacity(ids.len());
+        for chunk in self.fetch_info_chunks(ids, concurrency) {
+            posts.extend(chunk?);
+        }
+        Ok(posts)
+    }
+
+    /// Fetches the submissions with the specified full names like
+    /// `RedditClient.get_all_by_ids()`, but skips invalid IDs and chunks that could not be
+    /// fetched instead of failing, which suits bulk jobs where some posts may be gone.
+    pub fn get_all_by_ids_tolerant(&self, ids: &[&str], concurrency: usize) -> Vec<Submission<'_>> {
+        let ids = ids.iter()
+            .filter(|id| Fullname::from_str(id).and_then(|id| id.expect_kind("t3")).is_ok())
+            .cloned()
+            .collect::<Vec<_>>();
+        self.fetch_info_chunks(&ids, concurrency)
+            .into_iter()
+            .filter_map(Result::ok)
+            .flatten()
+            .collect()
+    }
+
+    fn fetch_info_chunks(&self, ids: &[&str], concurrency: usize)
+                         -> Vec<Result<Vec<Submission<'_>>, APIError>> {
+        let chunks = ids.chunks(100).map(|chunk| chunk.join(",")).collect::<Vec<_>>();
+        let results = self.batch(&chunks, concurrency, |chunk| {
+            let path = format!("/api/info?id={}&raw_json=1", chunk);
+            let result = self.get_json(&path, false)?;
+            let posts: listing::Listing = from_str(&result)?;
+            Ok(posts.data.children)
+        });
+        results.into_iter()
+            .map(|chunk| {
+                chunk.map(|posts| {
+                    posts.into_iter().map(|post| Submission::new(self, post.data)).collect()
+                })
+            })
+            .collect()
+    }
+
+    /// Blocks the user with the specified name, so that they can no longer send private
+    /// messages to the logged-in account. This is the same as `client.user(name).block()`.
+    pub fn block_user_by_name(&self, username: &str) -> Result<(), APIError> {
+        self.user(username).block()
+    }
+
+    /// Blocks every user with the specified names, sending up to 4 requests at once. See
+    /// `RedditClient.batch()`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let spammers = ["spammer1", "spammer2"];
+    /// for (name, result) in spammers.iter().zip(client.block_users_by_name(&spammers)) {
+    ///     if let Err(err) = result {
+    ///         println!("Could not block {}: {:?}", name, err);
+    ///     }
+    /// }
+    /// ```
+    pub fn block_users_by_name(&self, usernames: &[&str]) -> Vec<Result<(), APIError>> {
+        self.batch(usernames, 4, |name| self.block_user_by_name(name))
+    }
+
+    /// Approves the item (submission or comment) with the specified full name, without fetching
+    /// it first. This is the same as `Approvable.approve()`, for when only the full name is
+    /// known (e.g. from a webhook). An ID that is not a full name gives an
+    /// `APIError::InvalidFullname`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// client.approve("t3_4uule8").expect("Could not approve");
+    /// client.remove("t1_d5t5q3c", true).expect("Could not remove");
+    /// client.lock("t3_4uule8").expect("Could not lock");
+    /// ```
+    pub fn approve(&self, fullname: &str) -> Result<(), APIError> {
+        let body = format!("id={}", Fullname::from_str(fullname)?);
+        self.post_success("/api/approve", &body, false)
+    }
+
+    /// Removes the item (submission or comment) with the specified full name, without fetching
+    /// it first. If `spam` is true, the item is also flagged for the spam filter. See
+    /// `RedditClient.approve()`.
+    pub fn remove(&self, fullname: &str, spam: bool) -> Result<(), APIError> {
+        let body = format!("id={}&spam={}", Fullname::from_str(fullname)?, spam);
+        self.post_success("/api/remove", &body, false)
+    }
+
+    /// Locks the item (submission or comment) with the specified full name, without fetching it
+    /// first, so that no new comments can be posted. See `RedditClient.approve()`.
+    pub fn lock(&self, fullname: &str) -> Result<(), APIError> {
+        let body = format!("id={}", Fullname::from_str(fullname)?);
+        self.post_success("/api/lock", &body, false)
+    }
+
+    /// Unlocks the item (submission or comment) with the specified full name, without fetching
+    /// it first. See `RedditClient.lock()`.
+    pub fn unlock(&self, fullname: &str) -> Result<(), APIError> {
+        let body = format!("id={}", Fullname::from_str(fullname)?);
+        self.post_success("/api/unlock", &body, false)
+    }
+
+    /// Approves every item (submission or comment) with the specified full names, sending up to
+    /// 4 requests at once. See `RedditClient.batch()`.
+    pub fn approve_many(&self, fullnames: &[&str]) -> Vec<Result<(), APIError>> {
+        self.batch(fullnames, 4, |name| self.approve(name))
+    }
+
+    /// Removes every item (submission or comment) with the specified full names, sending up to
+    /// 4 requests at once. If `spam` is true, the items are also flagged for the spam filter.
+    pub fn remove_many(&self, fullnames: &[&str], spam: bool) -> Vec<Result<(), APIError>> {
+        self.batch(fullnames, 4, |name| self.remove(name, spam))
+    }
+
+    /// Reports a user account (rather than a specific submission or comment) to the Reddit
+    /// admins. To report content to the moderators of a subreddit, use `Reportable.report()`
+    /// instead. The known `reason` values are provided by `UserReportReason`, but any reason
+    /// string accepted by the API can be used.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::UserReportReason;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let reason = UserReportReason::Spam;
+    /// client.report_user("spammer", reason.as_str(), Some("Posts the same link everywhere"))
+    ///     .expect("Report failed");
+    /// ```
+    pub fn report_user(&self,
+                       username: &str,
+                       reason: &str,
+                       details: Option<&str>)
+                       -> Result<(), APIError> {
+        let mut body = format!("user={}&reason={}",
+                               self.url_escape(username.to_owned()),
+                               self.url_escape(reason.to_owned()));
+        if let Some(details) = details {
+            body = format!("{}&details={}", body, self.url_escape(details.to_owned()));
+        }
+        self.post_success("/api/report_user", &body, false)
+    }
 }
 
 impl Drop for RedditClient {
     fn drop(&mut self) {
         if self.auto_logout {
-            let result = self.get_authenticator().logout(&self.client, &self.user_agent);
-            if result.is_err() {
-                println!("{}", result.err().unwrap());
-            }
+            // Errors cannot be returned from `drop`, and the tokens expire anyway if revoking
+            // them fails.
+            let _ = self.get_authenticator().logout(&self.client, &self.user_agent);
         }
     }
 }
@@ -0,0 +1,76 @@
+//! The HTTP transport that sends the requests of a `RedditClient`. By default, requests are
+//! sent with `hyper` and `hyper-tls`. With the `reqwest` feature, they are sent with `reqwest`
+//! instead, so programs that already use `reqwest` do not need a second HTTP client and TLS
+//! stack:
+//!
+//! ```toml
+//! [dependencies]
+//! new-rawr = { version = "0.0.1", default-features = false, features = ["reqwest"] }
+//! ```
+//!
+//! Either way, requests and responses use the `hyper` types and `HttpClient` is an opaque type,
+//! so the API is the same with both features. Enabling `reqwest` anywhere in a dependency graph
+//! does not break code (such as a custom `Authenticator`) written against the default transport.
+
+use hyper::{Body, Request, Response};
+
+use crate::errors::APIError;
+
+#[cfg(not(any(feature = "hyper-tls", feature = "reqwest")))]
+compile_error!("new_rawr needs an HTTP transport: enable the `hyper-tls` or `reqwest` feature");
+
+/// The HTTP client used to send requests. Use `send()` to send a request with it. Its
+/// implementation depends on the enabled transport feature, but its API does not.
+#[derive(Clone)]
+pub struct HttpClient {
+    #[cfg(not(feature = "reqwest"))]
+    inner: hyper::Client<hyper_tls::HttpsConnector<hyper::client::HttpConnector>>,
+    #[cfg(feature = "reqwest")]
+    inner: reqwest::Client,
+}
+
+/// Creates the HTTP client for a new `RedditClient`.
+#[cfg(not(feature = "reqwest"))]
+pub fn new_client() -> HttpClient {
+    let https = hyper_tls::HttpsConnector::new();
+    HttpClient { inner: hyper::Client::builder().build::<_, Body>(https) }
+}
+
+/// Creates the HTTP client for a new `RedditClient`.
+#[cfg(feature = "reqwest")]
+pub fn new_client() -> HttpClient {
+    // Some endpoints answer with a redirect that is handled by new_rawr itself, so redirects
+    // must not be followed. Each request runs on its own runtime, so connections cannot be
+    // reused between requests either.
+    let inner = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .pool_max_idle_per_host(0)
+        .build()
+        .expect("Unable to create the HTTP client");
+    HttpClient { inner }
+}
+
+/// Sends the request and waits for the response.
+#[cfg(not(feature = "reqwest"))]
+pub async fn send(client: &HttpClient, request: Request<Body>) -> Result<Response<Body>, APIError> {
+    Ok(client.inner.request(request).await?)
+}
+
+/// Sends the request and waits for the response. The response body is read completely before
+/// it is returned.
+#[cfg(feature = "reqwest")]
+pub async fn send(client: &HttpClient, request: Request<Body>) -> Result<Response<Body>, APIError> {
+    let (parts, body) = request.into_parts();
+    let body = hyper::body::to_bytes(body).await?;
+    let response = client.inner.request(parts.method, parts.uri.to_string())
+        .headers(parts.headers)
+        .body(body)
+        .send()
+        .await?;
+    let mut builder = Response::builder().status(response.status());
+    if let Some(headers) = builder.headers_mut() {
+        *headers = response.headers().clone();
+    }
+    let body = response.bytes().await?;
+    Ok(builder.body(Body::from(body)).expect("Invalid response"))
+}
@@ -2,8 +2,12 @@ use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use hyper;
-use hyper::StatusCode;
+/// The HTTP status code type used by `APIError::HTTPError`. It comes from the `http` crate,
+/// which both transports use, so it is the same type whichever transport feature is enabled.
+/// It is re-exported so that errors can be matched without depending on `hyper`.
+pub use hyper::StatusCode;
 use serde_json;
+use serde_json::Value;
 
 /// Error type that occurs when an API request fails for some reason.
 #[derive(Debug)]
@@ -17,43 +21,182 @@ pub enum APIError {
     HTTPError(StatusCode),
     /// Occurs if the HTTP response from Reddit was corrupt and Hyper could not parse it.
     HyperError(hyper::Error),
+    /// Occurs if the request could not be sent or the response could not be read by `reqwest`,
+    /// which is used instead of Hyper when the `reqwest` feature is enabled.
+    #[cfg(feature = "reqwest")]
+    ReqwestError(reqwest::Error),
     /// Occurs if JSON deserialization fails. This will always be a bug, so please report it
     /// if it does occur, but the error type is provided so you can fail gracefully.
     JSONError(serde_json::Error),
     ///The token has expired.
     ExpiredToken,
+    /// Occurs when the authenticator could not log in, e.g. because the username or password is
+    /// wrong. Contains the reason, such as `invalid_grant`.
+    AuthenticationFailed(String),
+    /// Occurs when trying to edit something that has no editable body, such as a link post.
+    NotEditable,
+    /// Occurs when the request was received successfully, but Reddit refused it and reported
+    /// an error in the response body (e.g. inviting a user who is already a contributor).
+    RedditError(RedditError),
+    /// Occurs when an ID is not a valid full name (`{kind}_{id}`, e.g. `t3_4uule8`), or is the
+    /// full name of the wrong kind of item. Contains the rejected ID.
+    InvalidFullname(String),
+    /// Occurs when a URL is not a link to a Reddit post. Contains the rejected URL.
+    InvalidURL(String),
+    /// Occurs when a flair template ID is not a UUID (e.g.
+    /// `b8a1bc8a-1d6a-11e6-a1d4-0e5f4c9d5a5f`). Contains the rejected ID.
+    InvalidTemplateId(String),
+    /// Occurs when replying to an archived post or comment, which can no longer receive replies
+    /// (Reddit archives posts after 6 months).
+    Archived,
+    /// Occurs when a request that was sent from another thread (e.g. by `RedditClient.batch()`)
+    /// panicked instead of returning its result.
+    RequestPanicked,
 }
 
-impl Display for APIError {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "Error! {}. ({:?})", self, self)
+/// An error reported by Reddit in the `json.errors` array of an `api_type=json` response.
+#[derive(Debug)]
+pub struct RedditError {
+    /// The error code, e.g. `USER_DOESNT_EXIST`.
+    pub code: String,
+    /// The human readable explanation of the error.
+    pub message: String,
+    /// The request field that caused the error, if any.
+    pub field: Option<String>,
+}
+
+impl RedditError {
+    /// Gets the first error from the `json.errors` array of a response, if there is one.
+    pub fn from_response(response: &Value) -> Option<RedditError> {
+        let error = response["json"]["errors"].as_array()?.first()?;
+        let part = |i: usize| error.get(i).and_then(Value::as_str).map(str::to_owned);
+        Some(RedditError {
+            code: part(0).unwrap_or_default(),
+            message: part(1).unwrap_or_default(),
+            field: part(2),
+        })
     }
 }
 
-impl Error for APIError {
-    fn description(&self) -> &str {
+impl APIError {
+    /// A short explanation of the kind of error, without its details.
+    fn message(&self) -> &str {
         match *self {
             APIError::HTTPError(_) => "The API returned a non-success error code",
             APIError::HyperError(_) => "An error occurred while processing the HTTP response",
+            #[cfg(feature = "reqwest")]
+            APIError::ReqwestError(_) => "An error occurred while sending the HTTP request",
             APIError::JSONError(_) => {
                 "The JSON sent by Reddit did not match what new_rawr was expecting"
             }
             APIError::ExpiredToken => {
                 "ExpiredToken"
             }
+            APIError::AuthenticationFailed(_) => "Authentication failed",
+            APIError::NotEditable => "Only self posts, comments and messages can be edited",
+            APIError::RedditError(_) => "Reddit refused the request",
+            APIError::InvalidFullname(_) => "The ID is not a valid full name",
+            APIError::InvalidURL(_) => "Not a Reddit URL",
+            APIError::InvalidTemplateId(_) => "The ID is not a valid flair template ID",
+            APIError::Archived => "Archived posts and comments cannot be replied to",
+            APIError::RequestPanicked => "A request panicked on another thread",
             _ => "This error should not have occurred. Please file a bug",
         }
     }
 }
 
+impl Display for APIError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            APIError::HTTPError(status) => write!(f, "{} ({})", self.message(), status),
+            APIError::HyperError(ref err) => write!(f, "{}: {}", self.message(), err),
+            #[cfg(feature = "reqwest")]
+            APIError::ReqwestError(ref err) => write!(f, "{}: {}", self.message(), err),
+            APIError::JSONError(ref err) => write!(f, "{}: {}", self.message(), err),
+            APIError::RedditError(ref err) => {
+                write!(f, "{}: {} ({})", self.message(), err.message, err.code)
+            }
+            APIError::AuthenticationFailed(ref detail) |
+            APIError::InvalidFullname(ref detail) |
+            APIError::InvalidURL(ref detail) |
+            APIError::InvalidTemplateId(ref detail) => write!(f, "{}: {}", self.message(), detail),
+            _ => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl Error for APIError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
 impl From<hyper::Error> for APIError {
     fn from(err: hyper::Error) -> APIError {
         APIError::HyperError(err)
     }
 }
 
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for APIError {
+    fn from(err: reqwest::Error) -> APIError {
+        APIError::ReqwestError(err)
+    }
+}
+
 impl From<serde_json::Error> for APIError {
     fn from(err: serde_json::Error) -> APIError {
         APIError::JSONError(err)
     }
 }
+
+/// Error type returned by `Subreddit.submit_text_validated()` and
+/// `Subreddit.submit_link_validated()` when a post does not meet the subreddit's requirements.
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The title is longer than the subreddit (or Reddit) allows. The maximum length is given.
+    TitleTooLong(usize),
+    /// The title does not contain any of the strings that the subreddit requires. The required
+    /// strings are given.
+    TitleMissingRequired(Vec<String>),
+    /// The title contains a string that the subreddit does not allow, which is given.
+    TitleBlacklisted(String),
+    /// The subreddit requires self posts to have a body, but the body is empty.
+    BodyRequired,
+    /// Links to this domain are not allowed in the subreddit.
+    DomainBlocked(String),
+    /// The subreddit requires a flair for every post.
+    FlairRequired,
+    /// The requirements could not be fetched, or the submission itself failed.
+    APIError(APIError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            SubmitError::TitleTooLong(max) => {
+                write!(f, "The title is longer than {} characters", max)
+            }
+            SubmitError::TitleMissingRequired(ref strings) => {
+                write!(f, "The title must contain one of: {}", strings.join(", "))
+            }
+            SubmitError::TitleBlacklisted(ref string) => {
+                write!(f, "The title must not contain '{}'", string)
+            }
+            SubmitError::BodyRequired => write!(f, "The subreddit requires a post body"),
+            SubmitError::DomainBlocked(ref domain) => {
+                write!(f, "Links to {} are not allowed in the subreddit", domain)
+            }
+            SubmitError::FlairRequired => write!(f, "The subreddit requires a post flair"),
+            SubmitError::APIError(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<APIError> for SubmitError {
+    fn from(err: APIError) -> SubmitError {
+        SubmitError::APIError(err)
+    }
+}
@@ -220,18 +220,24 @@ pub mod options;
 /// Basic `new_rawr` structures to import with `use new_rawr::prelude::*`;
 #[cfg(test)]
 mod tests {
-    use hyper::Client;
+    use hyper::{Body, Client};
     use crate::auth::{AnonymousAuthenticator, PasswordAuthenticator};
     use crate::client::RedditClient;
-    use crate::options::ListingOptions;
-    use crate::responses::listing::SubmissionData;
+    use crate::errors::{APIError, StatusCode};
+    use crate::options::{FlairTemplateSpec, FlairType, ListingOptions};
+    use crate::responses::comment::MoreData;
+    use crate::responses::listing::{SubmissionData, SubredditSettings};
+    use crate::structures::comment_list::CommentList;
+    use crate::structures::listing::{Listing, MixedItem, MixedListing};
     use crate::structures::submission::Submission;
+    use crate::traits::{Content, PageListing};
 
     extern crate dotenv;
 
 
     use dotenv::dotenv;
     use std::env;
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn hot_length() {
@@ -241,6 +247,296 @@ mod tests {
         let hot_list = hot.take(26).collect::<Vec<Submission>>();
         assert_eq!(hot_list.len() as usize, 26);
     }
+    #[test]
+    fn raw_json_added_once() {
+        let mut client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let request = client.get("/r/all/hot?limit=25&raw_json=1&", false).unwrap().body(Body::empty()).unwrap();
+        assert_eq!(request.uri().query(), Some("limit=25&raw_json=1"));
+        let request = client.post("/api/comment", false).unwrap().body(Body::empty()).unwrap();
+        assert_eq!(request.uri().query(), Some("raw_json=1"));
+
+        client.set_raw_json(false);
+        let request = client.get("/r/all/hot?raw_json=1&limit=25", false).unwrap().body(Body::empty()).unwrap();
+        assert_eq!(request.uri().query(), Some("limit=25"));
+    }
+
+    #[test]
+    fn listing_limit_stops_fetching() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        // The listing has another page, but it must not be requested once the limit is reached.
+        let data = serde_json::from_str(r#"{"after": "t3_4uule8", "children": []}"#).unwrap();
+        let listing = Listing::new(&client, String::from("/r/all/new?limit=25"), data);
+        assert_eq!(listing.limit(0).count(), 0);
+    }
+
+    /// Creates an anonymous client that records the URL of every request it sends.
+    fn recording_client() -> (RedditClient, Arc<Mutex<Vec<String>>>) {
+        let mut client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let seen = urls.clone();
+        client.set_on_request(Box::new(move |request| {
+            seen.lock().unwrap().push(request.url.to_owned());
+        }));
+        (client, urls)
+    }
+
+    #[test]
+    fn listing_limit_uses_current_page() {
+        let (client, urls) = recording_client();
+        let data = serde_json::from_value(submission_page(5, "t3_x4")).unwrap();
+        let listing = Listing::new(&client, String::from("/r/all/new?limit=5"), data);
+        assert_eq!(listing.limit(5).count(), 5);
+        assert!(urls.lock().unwrap().is_empty());
+
+        // Skipped stickies do not count towards the limit, so the posts after them on the
+        // same page are still yielded instead of fetching the next page.
+        let mut page = submission_page(3, "t3_x2");
+        page["children"][0]["data"]["stickied"] = serde_json::Value::Bool(true);
+        let data = serde_json::from_value(page).unwrap();
+        let listing = Listing::new(&client, String::from("/r/all/new?limit=3"), data);
+        let names = listing.without_stickies()
+            .limit(2)
+            .map(|post| post.name().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["t3_x1", "t3_x2"]);
+        assert!(urls.lock().unwrap().is_empty());
+    }
+
+    /// Builds a listing page with `n` minimal submissions, followed by the `after` token.
+    fn submission_page(n: usize, after: &str) -> serde_json::Value {
+        let children = (0..n).map(|i| serde_json::json!({
+            "kind": "t3",
+            "data": {
+                "domain": "self.rust", "subreddit": "rust", "selftext": "", "id": format!("x{}", i),
+                "gilded": 0, "archived": false, "clicked": false, "author": "new_rawr",
+                "score": 1, "over_18": false, "hidden": false, "num_comments": 0,
+                "thumbnail": "self", "subreddit_id": "t5_2s7lj", "hide_score": false,
+                "edited": false, "downs": 0, "ups": 1, "saved": false, "stickied": false,
+                "is_self": true, "permalink": "/r/rust/", "locked": false,
+                "name": format!("t3_x{}", i), "created": 0.0, "quarantine": false,
+                "title": "Test", "created_utc": 0.0, "visited": false
+            }
+        })).collect::<Vec<_>>();
+        serde_json::json!({ "after": after, "children": children })
+    }
+
+    #[test]
+    fn listing_sends_running_count() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let data = serde_json::from_value(submission_page(25, "t3_x24")).unwrap();
+        let mut listing = Listing::new(&client, String::from("/r/all/new?limit=25"), data);
+        // The first page was 25 items long, so the second page starts at 25...
+        assert_eq!(listing.next_page_url().unwrap(),
+                   "/r/all/new?limit=25&count=25&after=t3_x24");
+        // ...and the third at 50, even if items were already consumed from the listing.
+        listing.by_ref().take(10).count();
+        listing.push_page(serde_json::from_value(submission_page(25, "t3_y24")).unwrap());
+        assert_eq!(listing.next_page_url().unwrap(),
+                   "/r/all/new?limit=25&count=50&after=t3_y24");
+    }
+
+    #[test]
+    fn mixed_listing_reports_bad_children() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let mut page = submission_page(1, "t3_x0");
+        let children = page["children"].as_array_mut().unwrap();
+        children.insert(0, serde_json::json!({"kind": "t4", "data": {}}));
+        children.push(serde_json::json!({"kind": "t1", "data": {"body": 1}}));
+        let data = serde_json::from_value(page).unwrap();
+        let mut listing = MixedListing::new(&client, String::from("/user/new_rawr/overview?"), data);
+        // The message is skipped, and the broken comment ends the listing with an error.
+        assert!(matches!(listing.next(), Some(MixedItem::Submission(_))));
+        assert!(listing.next().is_none());
+        assert!(matches!(listing.last_error(), Some(APIError::JSONError(_))));
+        assert!(listing.after().is_none());
+    }
+
+    #[test]
+    fn reversed_listing_sends_running_count() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let mut page = submission_page(25, "t3_x24");
+        page["before"] = serde_json::Value::from("t3_x0");
+        let data = serde_json::from_value(page).unwrap();
+        let listing = Listing::new(&client, String::from("/r/all/new?limit=25"), data).reversed();
+        assert_eq!(listing.prev_page_url().unwrap(),
+                   "/r/all/new?limit=25&count=25&before=t3_x0");
+    }
+
+    #[test]
+    fn before_paging_sends_running_count() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let sub = client.subreddit("rust");
+        assert_eq!(sub.before_page_url("new", "t3_x0", 25, 0),
+                   "/r/rust/new?count=0&before=t3_x0&limit=25&raw_json=1");
+        // The second page starts before the first post of the first page.
+        assert_eq!(sub.before_page_url("comments", "t1_y24", 25, 25),
+                   "/r/rust/comments?count=25&before=t1_y24&limit=25&raw_json=1");
+    }
+
+    #[test]
+    fn submission_data_round_trips() {
+        let mut payload = submission_page(1, "t3_x0")["children"][0]["data"].clone();
+        payload["author"] = serde_json::Value::Null;
+        payload["mod_reports"] = serde_json::json!([["Spam", "new_rawr"]]);
+        payload["user_reports"] = serde_json::json!([["Spam", 2, false, false]]);
+        let data: SubmissionData = serde_json::from_value(payload).unwrap();
+        let saved = serde_json::to_string(&data).unwrap();
+        let loaded: SubmissionData = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.author, "[deleted]");
+        assert_eq!(loaded.mod_reports, vec![(String::from("Spam"), String::from("new_rawr"))]);
+        assert_eq!(loaded.user_reports, vec![(String::from("Spam"), 2)]);
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), serde_json::to_value(&data).unwrap());
+    }
+
+    #[test]
+    fn continue_this_thread_is_fetched_from_thread() {
+        let more = serde_json::json!({"count": 0, "parent_id": "t1_d5t5q3c", "children": []});
+        let more: MoreData = serde_json::from_value(more).unwrap();
+        assert_eq!(CommentList::continue_thread_url("t3_4uule8", &more).unwrap().unwrap(),
+                   "/comments/4uule8/_/d5t5q3c?raw_json=1");
+
+        let more = serde_json::json!({"count": 2, "parent_id": "t1_d5t5q3c",
+                                      "children": ["d5t6ab1", "d5t6ab2"]});
+        let more: MoreData = serde_json::from_value(more).unwrap();
+        assert!(CommentList::continue_thread_url("t3_4uule8", &more).unwrap().is_none());
+    }
+
+    #[test]
+    fn invalid_flair_template_id_is_rejected() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let spec = FlairTemplateSpec::new("Solved", FlairType::Link);
+        // The ID is checked before anything is sent, so this does not reach Reddit.
+        let result = client.subreddit("new_rawr").edit_flair_template("t3_4uule8", spec);
+        assert!(matches!(result, Err(APIError::InvalidTemplateId(id)) if id == "t3_4uule8"));
+    }
+
+    #[test]
+    fn request_hook_sees_sent_request() {
+        let mut client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let seen = urls.clone();
+        client.set_on_request(Box::new(move |request| {
+            assert!(!request.oauth);
+            seen.lock().unwrap().push(request.url.to_owned());
+        }));
+        // The hook runs before the request is sent, so the result does not matter here.
+        let _ = client.subreddit("rust").sticky(1);
+        assert_eq!(urls.lock().unwrap()[0],
+                   "https://api.reddit.com/r/rust/about/sticky?num=1&limit=0&raw_json=1");
+    }
+
+    #[test]
+    fn api_error_display_includes_details() {
+        let err = APIError::AuthenticationFailed(String::from("invalid_grant"));
+        assert_eq!(err.to_string(), "Authentication failed: invalid_grant");
+        let err = APIError::HTTPError(hyper::StatusCode::NOT_FOUND);
+        assert_eq!(err.to_string(), "The API returned a non-success error code (404 Not Found)");
+    }
+
+    #[test]
+    fn site_admin_sends_every_setting() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let settings = serde_json::json!({
+            "subreddit_id": "t5_2qh1i", "title": "New rawr", "subreddit_type": "public",
+            "content_options": "any", "language": "en", "spam_links": "high",
+            "wiki_edit_karma": 100, "public_traffic": false, "suggested_comment_sort": null
+        });
+        let settings: SubredditSettings = serde_json::from_value(settings).unwrap();
+        let body = client.subreddit("new_rawr").site_admin_body(settings).unwrap();
+        let params = body.split('&').collect::<Vec<_>>();
+        for param in &["api_type=json", "name=new_rawr", "sr=t5_2qh1i", "title=New+rawr",
+                       "type=public", "link_type=any", "lang=en", "spam_links=high",
+                       "wiki_edit_karma=100", "public_traffic=false"] {
+            assert!(params.contains(param), "{} is missing from {}", param, body);
+        }
+        assert!(!body.contains("suggested_comment_sort"));
+    }
+
+    #[test]
+    fn comment_list_keeps_fetch_error() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let more = serde_json::json!({"kind": "more",
+                                      "data": {"count": 0, "parent_id": "bogus", "children": []}});
+        let more = serde_json::from_value(more).unwrap();
+        let mut list = CommentList::new(&client, String::from("t3_x"), String::from("t3_x"),
+                                        vec![more]);
+        assert!(list.next().is_none());
+        match list.last_error() {
+            Some(APIError::InvalidFullname(name)) => assert_eq!(name, "bogus"),
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn names_compare_case_insensitively() {
+        use std::collections::HashSet;
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        assert_eq!(client.subreddit("Rust"), client.subreddit("rust"));
+        assert_eq!(client.user("Spez"), client.user("spez"));
+        let subs = vec![client.subreddit("Rust"), client.subreddit("rust")];
+        assert_eq!(subs.into_iter().collect::<HashSet<_>>().len(), 1);
+    }
+
+    #[test]
+    fn multipart_body_escapes_parameters() {
+        let fields = vec![("title\"\r\nX-Injected: 1", "value")];
+        let file = Some(("a\"b.png", &b"data"[..], "image/png"));
+        let (content_type, form) = RedditClient::multipart_body(fields.into_iter(), file);
+        let form = String::from_utf8(form).unwrap();
+        assert!(form.contains("name=\"title%22%0D%0AX-Injected: 1\""));
+        assert!(form.contains("filename=\"a%22b.png\""));
+        let boundary = content_type.split("boundary=").nth(1).unwrap();
+        assert!(form.ends_with(&format!("--{}--\r\n", boundary)));
+        let (other, _) = RedditClient::multipart_body(Vec::new().into_iter(), None);
+        assert_ne!(content_type, other);
+    }
+
+    #[test]
+    fn raw_json_sent_for_feeds_submissions_and_comments() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let feeds = ["/r/all/hot?limit=25&raw_json=1&",
+                     "/r/all/hot?limit=25&count=25&after=t3_x24"];
+        for feed in &feeds {
+            let request = client.get(feed, false).unwrap().body(Body::empty()).unwrap();
+            let query = request.uri().query().unwrap();
+            assert_eq!(query.matches("raw_json=1").count(), 1, "{}", query);
+        }
+        for dest in &["/api/submit", "/api/comment"] {
+            let request = client.post(dest, false).unwrap().body(Body::empty()).unwrap();
+            assert_eq!(request.uri().query(), Some("raw_json=1"));
+        }
+    }
+
+    /// Checks the live /r/all feed for escaped titles. This needs network access.
+    #[test]
+    #[ignore]
+    fn raw_json_titles_unescaped() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let r_all = client.subreddit("all");
+        let hot = r_all.hot(ListingOptions::default()).expect("Request failed!");
+        for post in hot.take(100) {
+            assert!(!post.title().contains("&amp;"), "Escaped title: {}", post.title());
+        }
+    }
+
+    /// Checks that private and missing subreddits give their HTTP status instead of panicking.
+    /// This needs network access.
+    #[test]
+    #[ignore]
+    fn unavailable_subreddit_errors() {
+        let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+        let unavailable = |result: Result<_, APIError>| match result {
+            Err(APIError::HTTPError(StatusCode::FORBIDDEN)) |
+            Err(APIError::HTTPError(StatusCode::NOT_FOUND)) => {}
+            Err(err) => panic!("Unexpected error: {}", err),
+            Ok(_) => panic!("The subreddit should not be available"),
+        };
+        // /r/lounge is private and the other subreddit does not exist.
+        unavailable(client.subreddit("lounge").about().map(|_| ()));
+        unavailable(client.subreddit("lounge").hot(ListingOptions::default()).map(|_| ()));
+        unavailable(client.subreddit("new_rawr_no_such_subreddit").about().map(|_| ()));
+    }
+
     #[test]
     fn user_data_test() {
         let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
@@ -272,7 +568,7 @@ mod tests {
         println!("Hey2");
         let subreddit = client.subreddit("new_rawr");
         println!("Hey3");
-        let result = subreddit.invite_member("LordPenguin42".parse().unwrap());
+        let result = subreddit.add_approved_user("LordPenguin42");
         if result.is_err() {
             println!("{}", result.err().unwrap());
         }
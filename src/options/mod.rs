@@ -1,11 +1,20 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use serde::Serialize;
+
 /// Configures a paginated listing.
 pub struct ListingOptions {
     /// The maximum amount of posts to fetch in one request.
     pub batch: u8,
     /// See `ListingAnchor` for explanation of this property.
     pub anchor: ListingAnchor,
+    /// If `true`, stickied (announcement) posts are skipped when iterating the listing. This is
+    /// mainly useful for the hot feed, where the first page starts with any stickied posts.
+    pub exclude_sticky: bool,
+    /// If `true`, NSFW (18+) posts are included in the listing. This only has an effect for
+    /// logged-in accounts that have enabled 18+ content in their preferences; anonymous
+    /// requests never receive NSFW posts in feeds.
+    pub include_nsfw: bool,
 }
 
 impl Display for ListingOptions {
@@ -21,8 +30,36 @@ impl ListingOptions {
         ListingOptions {
             batch: 25,
             anchor: ListingAnchor::None,
+            exclude_sticky: false,
+            include_nsfw: false,
         }
     }
+
+    /// Skips stickied posts when iterating the listing, so only the 'real' posts of the feed
+    /// are yielded.
+    /// # Examples
+    /// ```
+    /// use new_rawr::options::ListingOptions;
+    /// let opts = ListingOptions::default().no_stickies();
+    /// assert!(opts.exclude_sticky);
+    /// ```
+    pub fn no_stickies(mut self) -> ListingOptions {
+        self.exclude_sticky = true;
+        self
+    }
+
+    /// Includes NSFW (18+) posts in the listing. See `ListingOptions.include_nsfw` for the
+    /// requirements.
+    /// # Examples
+    /// ```
+    /// use new_rawr::options::ListingOptions;
+    /// let opts = ListingOptions::default().include_nsfw();
+    /// assert!(opts.include_nsfw);
+    /// ```
+    pub fn include_nsfw(mut self) -> ListingOptions {
+        self.include_nsfw = true;
+        self
+    }
 }
 
 /// Used to 'anchor' the pagination so you can get all posts before/after a post.
@@ -73,6 +110,7 @@ impl Display for TimeFilter {
 }
 
 /// Options used when creating a link post. See `structures::subreddit` for examples of usage.
+#[derive(Clone)]
 pub struct LinkPost {
     /// The title of the link post to create
     pub title: String,
@@ -81,6 +119,10 @@ pub struct LinkPost {
     pub link: String,
     /// True if resubmitting this link is intended.
     pub resubmit: bool,
+    /// The ID of the flair template to apply to the post.
+    pub flair_id: Option<String>,
+    /// The flair text to apply to the post, if the flair template allows editing it.
+    pub flair_text: Option<String>,
 }
 
 impl LinkPost {
@@ -91,6 +133,8 @@ impl LinkPost {
             title: title.to_owned(),
             link: link.to_owned(),
             resubmit: false,
+            flair_id: None,
+            flair_text: None,
         }
     }
 
@@ -105,14 +149,32 @@ impl LinkPost {
         self.resubmit = true;
         self
     }
+
+    /// Sets the flair template (and optionally the flair text) the post is submitted with.
+    /// # Examples
+    /// ```
+    /// use new_rawr::options::LinkPost;
+    /// let post = LinkPost::new("Look at this!", "http://example.com/foo")
+    ///     .flair("2a6b5a5e-5b36-11e9-a4b2-0e1b3d8f5a2a", Some("News"));
+    /// ```
+    pub fn flair(mut self, flair_id: &str, flair_text: Option<&str>) -> LinkPost {
+        self.flair_id = Some(flair_id.to_owned());
+        self.flair_text = flair_text.map(str::to_owned);
+        self
+    }
 }
 
 /// Options used when creating a self post. See `structures::subreddit` for examples of usage.
+#[derive(Clone)]
 pub struct SelfPost {
     /// The title of the link post to create
     pub title: String,
     /// The markdown post body.
     pub text: String,
+    /// The ID of the flair template to apply to the post.
+    pub flair_id: Option<String>,
+    /// The flair text to apply to the post, if the flair template allows editing it.
+    pub flair_text: Option<String>,
 }
 
 impl SelfPost {
@@ -122,6 +184,647 @@ impl SelfPost {
         SelfPost {
             title: title.to_owned(),
             text: text.to_owned(),
+            flair_id: None,
+            flair_text: None,
+        }
+    }
+
+    /// Sets the flair template (and optionally the flair text) the post is submitted with.
+    /// See `LinkPost::flair()`.
+    pub fn flair(mut self, flair_id: &str, flair_text: Option<&str>) -> SelfPost {
+        self.flair_id = Some(flair_id.to_owned());
+        self.flair_text = flair_text.map(str::to_owned);
+        self
+    }
+}
+
+/// The known reasons for reporting a user account to the Reddit admins with
+/// `RedditClient.report_user()`, which takes the reason as a string (see
+/// `UserReportReason.as_str()`).
+pub enum UserReportReason {
+    /// The account is posting spam.
+    Spam,
+    /// The account is threatening violence.
+    ThreateningViolence,
+    /// The account is sharing personal information.
+    PersonalInformation,
+    /// Any other reason string accepted by the API.
+    Other(String),
+}
+
+impl UserReportReason {
+    /// Gets the reason string that the API expects, e.g. `spam`.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserReportReason::Spam => "spam",
+            UserReportReason::ThreateningViolence => "threatening_violence",
+            UserReportReason::PersonalInformation => "personal_information",
+            UserReportReason::Other(ref reason) => reason,
+        }
+    }
+}
+
+impl Display for UserReportReason {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The order of results when searching a subreddit.
+#[allow(missing_docs)]
+pub enum SearchSort {
+    Relevance,
+    Hot,
+    Top,
+    New,
+    /// Sorts by the number of comments. Only available when searching.
+    Comments,
+}
+
+impl Display for SearchSort {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let s = match *self {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Hot => "hot",
+            SearchSort::Top => "top",
+            SearchSort::New => "new",
+            SearchSort::Comments => "comments",
+        };
+        write!(f, "sort={}", s)
+    }
+}
+
+/// The kind of results returned by a search. Each kind has its own listing type, so this is
+/// chosen by the search method: `Subreddit.search_with_sort()` finds posts, and
+/// `RedditClient.search_subreddits()` finds subreddits.
+pub enum SearchResultType {
+    /// Posts (`type=link`).
+    Link,
+    /// Subreddits (`type=sr`).
+    Subreddit,
+}
+
+impl Display for SearchResultType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let s = match *self {
+            SearchResultType::Link => "link",
+            SearchResultType::Subreddit => "sr",
+        };
+        write!(f, "type={}", s)
+    }
+}
+
+/// Configures the order, time span and pagination of search results.
+/// # Examples
+/// ```
+/// use new_rawr::options::{SearchOptions, SearchSort, TimeFilter};
+/// let opts = SearchOptions::default()
+///     .sort(SearchSort::Comments)
+///     .time(TimeFilter::Month);
+/// ```
+pub struct SearchOptions {
+    /// The order of the results. Defaults to `SearchSort::Relevance`.
+    pub sort: SearchSort,
+    /// The time span of the results. Defaults to `TimeFilter::AllTime`.
+    pub time: TimeFilter,
+    /// The batch size and anchor of the listing.
+    pub listing: ListingOptions,
+}
+
+impl Default for SearchOptions {
+    /// Provides the default options: the most relevant results of all time, 25 per page.
+    fn default() -> SearchOptions {
+        SearchOptions {
+            sort: SearchSort::Relevance,
+            time: TimeFilter::AllTime,
+            listing: ListingOptions::default(),
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Sets the order of the results.
+    pub fn sort(mut self, sort: SearchSort) -> SearchOptions {
+        self.sort = sort;
+        self
+    }
+
+    /// Restricts the results to the specified time span.
+    pub fn time(mut self, time: TimeFilter) -> SearchOptions {
+        self.time = time;
+        self
+    }
+
+    /// Sets the batch size and anchor of the listing.
+    pub fn listing(mut self, listing: ListingOptions) -> SearchOptions {
+        self.listing = listing;
+        self
+    }
+}
+
+/// The order of a user's submissions or comments.
+#[allow(missing_docs)]
+pub enum UserContentSort {
+    Hot,
+    /// The newest items first. This is the default on Reddit.
+    New,
+    Top,
+    Controversial,
+}
+
+impl Display for UserContentSort {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let s = match *self {
+            UserContentSort::Hot => "hot",
+            UserContentSort::New => "new",
+            UserContentSort::Top => "top",
+            UserContentSort::Controversial => "controversial",
+        };
+        write!(f, "sort={}", s)
+    }
+}
+
+/// Configures the order, time span and pagination of a user's submissions.
+/// # Examples
+/// ```
+/// use new_rawr::options::{TimeFilter, UserContentSort, UserSubmissionsOptions};
+/// let opts = UserSubmissionsOptions::default()
+///     .sort(UserContentSort::Top)
+///     .time(TimeFilter::Year);
+/// ```
+pub struct UserSubmissionsOptions {
+    /// The order of the submissions. Defaults to `UserContentSort::New`.
+    pub sort: UserContentSort,
+    /// The time span for the top and controversial orders. Ignored by Reddit for other orders.
+    pub time: Option<TimeFilter>,
+    /// The batch size and anchor of the listing.
+    pub listing: ListingOptions,
+}
+
+impl Default for UserSubmissionsOptions {
+    /// Provides the default options: the newest submissions first, 25 per page.
+    fn default() -> UserSubmissionsOptions {
+        UserSubmissionsOptions {
+            sort: UserContentSort::New,
+            time: None,
+            listing: ListingOptions::default(),
         }
     }
 }
+
+impl UserSubmissionsOptions {
+    /// Sets the order of the submissions.
+    pub fn sort(mut self, sort: UserContentSort) -> UserSubmissionsOptions {
+        self.sort = sort;
+        self
+    }
+
+    /// Restricts the top and controversial orders to the specified time span.
+    pub fn time(mut self, time: TimeFilter) -> UserSubmissionsOptions {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets the batch size and anchor of the listing.
+    pub fn listing(mut self, listing: ListingOptions) -> UserSubmissionsOptions {
+        self.listing = listing;
+        self
+    }
+}
+
+/// Builds a search query using Reddit's Lucene syntax, which supports searching specific fields
+/// and combining terms with boolean operators. Terms are joined with `AND` unless another
+/// operator is given.
+/// # Examples
+/// ```
+/// use new_rawr::options::LuceneQuery;
+/// let query = LuceneQuery::new().title("rust").flair("project").build();
+/// assert_eq!(query, "title:\"rust\" AND flair:\"project\"");
+/// let query = LuceneQuery::new().author("a").or().author("b").not().title("meta").build();
+/// assert_eq!(query, "author:\"a\" OR author:\"b\" AND NOT title:\"meta\"");
+/// ```
+#[derive(Default)]
+pub struct LuceneQuery {
+    parts: Vec<String>,
+    operator: Option<&'static str>,
+    negate: bool,
+}
+
+impl LuceneQuery {
+    /// Creates an empty query.
+    pub fn new() -> LuceneQuery {
+        LuceneQuery::default()
+    }
+
+    /// Matches the text anywhere in the post.
+    pub fn text(self, text: &str) -> LuceneQuery {
+        let term = LuceneQuery::quote(text);
+        self.push(term)
+    }
+
+    /// Matches posts with the text in the title.
+    pub fn title(self, text: &str) -> LuceneQuery {
+        self.field("title", text)
+    }
+
+    /// Matches posts with the specified link flair text.
+    pub fn flair(self, text: &str) -> LuceneQuery {
+        self.field("flair", text)
+    }
+
+    /// Matches posts submitted by the specified user (not including `/u/`).
+    pub fn author(self, name: &str) -> LuceneQuery {
+        self.field("author", name)
+    }
+
+    /// Matches link posts whose URL contains the text.
+    pub fn url(self, text: &str) -> LuceneQuery {
+        self.field("url", text)
+    }
+
+    /// Matches link posts to the specified domain, e.g. `github.com`.
+    pub fn site(self, domain: &str) -> LuceneQuery {
+        self.field("site", domain)
+    }
+
+    /// Matches self posts with the text in the body.
+    pub fn selftext(self, text: &str) -> LuceneQuery {
+        self.field("selftext", text)
+    }
+
+    /// Joins the previous and next terms with `AND` (the default).
+    pub fn and(mut self) -> LuceneQuery {
+        self.operator = Some("AND");
+        self
+    }
+
+    /// Joins the previous and next terms with `OR`.
+    pub fn or(mut self) -> LuceneQuery {
+        self.operator = Some("OR");
+        self
+    }
+
+    /// Excludes posts matching the next term.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> LuceneQuery {
+        self.negate = true;
+        self
+    }
+
+    /// Creates the query string. This is not URL encoded.
+    pub fn build(&self) -> String {
+        self.parts.join(" ")
+    }
+
+    fn field(self, name: &str, value: &str) -> LuceneQuery {
+        let term = format!("{}:{}", name, LuceneQuery::quote(value));
+        self.push(term)
+    }
+
+    fn push(mut self, term: String) -> LuceneQuery {
+        if !self.parts.is_empty() {
+            let operator = self.operator.take().unwrap_or("AND");
+            self.parts.push(operator.to_owned());
+        }
+        if self.negate {
+            self.negate = false;
+            self.parts.push(String::from("NOT"));
+        }
+        self.parts.push(term);
+        self
+    }
+
+    fn quote(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Options used when creating a gallery (multi-image) post. See `Subreddit.submit_gallery()`.
+pub struct GalleryPost {
+    /// The title of the gallery post to create.
+    pub title: String,
+    /// The images of the gallery, in the order they should be shown.
+    pub items: Vec<GalleryItem>,
+    /// True if the post should be marked NSFW (over 18).
+    pub nsfw: bool,
+    /// True if the post should be marked as a spoiler.
+    pub spoiler: bool,
+}
+
+impl GalleryPost {
+    /// Creates a new `GalleryPost` with the specified images. The post will not be submitted
+    /// until you use `Subreddit.submit_gallery()`.
+    pub fn new(title: &str, items: Vec<GalleryItem>) -> GalleryPost {
+        GalleryPost {
+            title: title.to_owned(),
+            items,
+            nsfw: false,
+            spoiler: false,
+        }
+    }
+
+    /// Marks this post as NSFW (over 18).
+    pub fn nsfw(mut self) -> GalleryPost {
+        self.nsfw = true;
+        self
+    }
+
+    /// Marks this post as a spoiler.
+    pub fn spoiler(mut self) -> GalleryPost {
+        self.spoiler = true;
+        self
+    }
+}
+
+/// A single image in a `GalleryPost`.
+pub struct GalleryItem {
+    /// The raw bytes of the image file.
+    pub image_data: Vec<u8>,
+    /// The MIME type of the image, e.g. `image/png` or `image/jpeg`.
+    pub mime_type: String,
+    /// An optional caption shown below the image.
+    pub caption: Option<String>,
+    /// An optional link shown with the image.
+    pub outbound_url: Option<String>,
+}
+
+impl GalleryItem {
+    /// Creates a `GalleryItem` from the image bytes and MIME type, without a caption or link.
+    pub fn new(image_data: Vec<u8>, mime_type: &str) -> GalleryItem {
+        GalleryItem {
+            image_data,
+            mime_type: mime_type.to_owned(),
+            caption: None,
+            outbound_url: None,
+        }
+    }
+}
+
+/// The order of comments in a comment tree.
+#[allow(missing_docs)]
+pub enum CommentSort {
+    /// The default 'best' sort.
+    Confidence,
+    Top,
+    New,
+    Controversial,
+    Old,
+    /// Question and answer mode, which shows the replies of the post author first.
+    QA,
+}
+
+impl Display for CommentSort {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let s = match *self {
+            CommentSort::Confidence => "confidence",
+            CommentSort::Top => "top",
+            CommentSort::New => "new",
+            CommentSort::Controversial => "controversial",
+            CommentSort::Old => "old",
+            CommentSort::QA => "qa",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Options used when banning a user from a subreddit. See `Subreddit.ban_user()`.
+/// # Examples
+/// ```
+/// use new_rawr::options::BanOptions;
+/// let opts = BanOptions::new("Spam")
+///     .duration(7)
+///     .with_message("You have been banned for 7 days for spamming.");
+/// assert_eq!(opts.duration, Some(7));
+/// ```
+pub struct BanOptions {
+    /// The reason for the ban, shown to other moderators (up to 100 characters).
+    pub reason: String,
+    /// The length of the ban in days (1 to 999), or `None` for a permanent ban.
+    pub duration: Option<u32>,
+    /// A note about the ban that is only shown to moderators (up to 300 characters).
+    pub note: Option<String>,
+    /// A message sent to the banned user along with the ban notification.
+    pub message: Option<String>,
+}
+
+impl BanOptions {
+    /// Creates the options for a permanent ban with the specified reason.
+    pub fn new(reason: &str) -> BanOptions {
+        BanOptions {
+            reason: reason.to_owned(),
+            duration: None,
+            note: None,
+            message: None,
+        }
+    }
+
+    /// Makes the ban temporary, lasting the specified number of days.
+    pub fn duration(mut self, days: u32) -> BanOptions {
+        self.duration = Some(days);
+        self
+    }
+
+    /// Adds a note about the ban that is only shown to moderators.
+    pub fn note(mut self, note: &str) -> BanOptions {
+        self.note = Some(note.to_owned());
+        self
+    }
+
+    /// Sends a message to the banned user along with the ban notification.
+    pub fn with_message(mut self, message: &str) -> BanOptions {
+        self.message = Some(message.to_owned());
+        self
+    }
+}
+
+/// Whether a flair template is for users or for posts.
+pub enum FlairType {
+    /// A flair shown next to a user's name (`USER_FLAIR`).
+    User,
+    /// A flair shown on a post (`LINK_FLAIR`).
+    Link,
+}
+
+impl Display for FlairType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let s = match *self {
+            FlairType::User => "USER_FLAIR",
+            FlairType::Link => "LINK_FLAIR",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Options used when creating or editing a flair template. See
+/// `Subreddit.create_flair_template()` and `Subreddit.edit_flair_template()`.
+/// # Examples
+/// ```
+/// use new_rawr::options::{FlairTemplateSpec, FlairType};
+/// let spec = FlairTemplateSpec::new("Solved", FlairType::Link)
+///     .background_color("#46d160")
+///     .text_color("light")
+///     .mod_only();
+/// assert!(spec.mod_only);
+/// ```
+pub struct FlairTemplateSpec {
+    /// The text of the flair.
+    pub text: String,
+    /// Whether the template is for user flairs or post flairs.
+    pub flair_type: FlairType,
+    /// The CSS class of the flair, used by old Reddit stylesheets.
+    pub css_class: Option<String>,
+    /// The color of the flair text, either `dark` or `light`.
+    pub text_color: Option<String>,
+    /// The background color of the flair as a hex code, e.g. `#46d160`.
+    pub background_color: Option<String>,
+    /// True if only moderators can assign this flair.
+    pub mod_only: bool,
+    /// True if users can change the text of the flair when assigning it.
+    pub text_editable: bool,
+}
+
+impl FlairTemplateSpec {
+    /// Creates a new `FlairTemplateSpec` with the default colors, which can be assigned by
+    /// anyone.
+    pub fn new(text: &str, flair_type: FlairType) -> FlairTemplateSpec {
+        FlairTemplateSpec {
+            text: text.to_owned(),
+            flair_type,
+            css_class: None,
+            text_color: None,
+            background_color: None,
+            mod_only: false,
+            text_editable: false,
+        }
+    }
+
+    /// Sets the CSS class of the flair.
+    pub fn css_class(mut self, css_class: &str) -> FlairTemplateSpec {
+        self.css_class = Some(css_class.to_owned());
+        self
+    }
+
+    /// Sets the color of the flair text, either `dark` or `light`.
+    pub fn text_color(mut self, text_color: &str) -> FlairTemplateSpec {
+        self.text_color = Some(text_color.to_owned());
+        self
+    }
+
+    /// Sets the background color of the flair as a hex code, e.g. `#46d160`.
+    pub fn background_color(mut self, background_color: &str) -> FlairTemplateSpec {
+        self.background_color = Some(background_color.to_owned());
+        self
+    }
+
+    /// Only allows moderators to assign this flair.
+    pub fn mod_only(mut self) -> FlairTemplateSpec {
+        self.mod_only = true;
+        self
+    }
+
+    /// Allows users to change the text of the flair when assigning it.
+    pub fn text_editable(mut self) -> FlairTemplateSpec {
+        self.text_editable = true;
+        self
+    }
+}
+
+/// How the posts of a collection are displayed on Reddit.
+pub enum CollectionLayout {
+    /// The posts are shown as a list, in the order they were added.
+    Timeline,
+    /// The posts are shown as a grid of thumbnails.
+    Gallery,
+}
+
+impl Display for CollectionLayout {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let s = match *self {
+            CollectionLayout::Timeline => "TIMELINE",
+            CollectionLayout::Gallery => "GALLERY",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Configures which part of a comment tree is fetched, so that large threads do not need to be
+/// loaded in full. By default, Reddit's defaults are used for all options.
+/// # Examples
+/// ```
+/// use new_rawr::options::{CommentSort, ReplyOptions};
+/// let opts = ReplyOptions::default().sort(CommentSort::Top).limit(10).depth(1);
+/// assert_eq!(opts.to_string(), "&sort=top&limit=10&depth=1");
+/// ```
+#[derive(Default)]
+pub struct ReplyOptions {
+    /// The order of the comments.
+    pub sort: Option<CommentSort>,
+    /// The maximum number of comments to fetch.
+    pub limit: Option<u32>,
+    /// The maximum depth of replies to fetch, where 1 only fetches top-level comments.
+    pub depth: Option<u32>,
+}
+
+impl ReplyOptions {
+    /// Sets the order of the comments.
+    pub fn sort(mut self, sort: CommentSort) -> ReplyOptions {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the maximum number of comments to fetch.
+    pub fn limit(mut self, limit: u32) -> ReplyOptions {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the maximum depth of replies to fetch.
+    pub fn depth(mut self, depth: u32) -> ReplyOptions {
+        self.depth = Some(depth);
+        self
+    }
+}
+
+impl Display for ReplyOptions {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if let Some(ref sort) = self.sort {
+            write!(f, "&sort={}", sort)?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, "&limit={}", limit)?;
+        }
+        if let Some(depth) = self.depth {
+            write!(f, "&depth={}", depth)?;
+        }
+        Ok(())
+    }
+}
+
+/// Changes to the preferences of the logged-in account. Only the fields that are `Some` are
+/// sent, so all other preferences are left unchanged. See `RedditClient.update_preferences()`.
+/// # Examples
+/// ```
+/// use new_rawr::options::AccountPreferenceUpdate;
+/// let update = AccountPreferenceUpdate {
+///     nightmode: Some(true),
+///     ..AccountPreferenceUpdate::default()
+/// };
+/// ```
+#[derive(Serialize, Default)]
+pub struct AccountPreferenceUpdate {
+    /// Shows or hides NSFW (over 18) content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub over_18: Option<bool>,
+    /// Enables or disables night mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nightmode: Option<bool>,
+    /// Hides or shows ads (Reddit Premium only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_ads: Option<bool>,
+    /// The default sort of comment trees, e.g. `confidence` or `new`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_comment_sort: Option<String>,
+    /// The interface language, e.g. `en`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+}
@@ -1,8 +1,9 @@
 /// A response providing an access token from /api/v1/access_token which can be used for the
 /// OAuth-based authenticators
 pub use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct TokenResponseData {
     pub access_token: String,
     pub expires_in: u64,
@@ -1,7 +1,9 @@
 use serde_json::Value;
 
 pub use serde::Deserialize;
-use crate::responses::{BasicThing, ThingList};
+use serde::Serialize;
+use crate::responses::{BasicThing, ThingList, deserialize_author, deserialize_mod_reports,
+                       deserialize_user_reports};
 use crate::responses::listing::ListingData;
 
 /// The 'listing' format for comments.
@@ -15,7 +17,7 @@ pub type NewComment = JSONWrapper<CommentThings>;
 /// A deserializable structure representing a comment. This is created when the client returns
 /// JSON representing a comment and this is wrapped in a `models::comment::Comment` for
 /// ease-of-use.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct CommentData {
     /// The Reddit ID for the subreddit where this was posted, **including the leading `t5_`**.
     pub subreddit_id: String,
@@ -33,7 +35,10 @@ pub struct CommentData {
     /// A listing of replies to this comment. Do not access this directly - use the functions
     /// of `Comment` instead.
     pub replies: Value,
-    // TODO: skipped user_reports
+    /// The reports made by users as `(reason, count)` pairs, if the logged-in user is a
+    /// moderator of this subreddit.
+    #[serde(default, deserialize_with = "deserialize_user_reports")]
+    pub user_reports: Vec<(String, u64)>,
     /// True if the logged-in user has saved this submission.
     pub saved: bool,
     /// The ID of the post in base-36 form, as used in Reddit's links.
@@ -44,7 +49,9 @@ pub struct CommentData {
     /// Archived submissions cannot be voted or commented upon.
     pub archived: bool,
     // TODO: skipped report_reasons
-    /// The name of the author of the submission (not including the leading `/u/`)
+    /// The name of the author of the submission (not including the leading `/u/`). This is
+    /// `[deleted]` if the account has been deleted.
+    #[serde(deserialize_with = "deserialize_author")]
     pub author: String,
     /// The overall points score of this post, as shown on the upvote counter. This is the
     /// same as upvotes - downvotes (however, this figure may be fuzzed by Reddit, and may not
@@ -53,7 +60,9 @@ pub struct CommentData {
     /// This contains the name of the user who approved this submission. This is `None` unless
     /// you are a mod of the subreddit **and** a user has approved this post.
     pub approved_by: Option<String>,
-    // TODO: skipped controversiality
+    /// 1 if the comment has received a similar number of upvotes and downvotes, 0 otherwise.
+    #[serde(default)]
+    pub controversiality: u8,
     /// The main post text.
     pub body: String,
     /// This is `false` if the submission is not edited and is the edit timestamp if it is edited.
@@ -98,29 +107,38 @@ pub struct CommentData {
     /// - Some("admin") - [A]
     /// - Some("special") - other special 'distinguishes' e.g. [Δ]
     pub distinguished: Option<String>,
-    pub num_reports: Option<u64>, // TODO: skipped mod_reports
-    pub parent_id: String
+    pub num_reports: Option<u64>,
+    /// The reports made by moderators as `(reason, moderator)` pairs, if the logged-in user is a
+    /// moderator of this subreddit.
+    #[serde(default, deserialize_with = "deserialize_mod_reports")]
+    pub mod_reports: Vec<(String, String)>,
+    pub parent_id: String,
+    /// How deeply the comment is nested, starting at 0 for top-level comments. This is only
+    /// present in comment trees, not in listings such as a user's comments.
+    pub depth: Option<u32>,
 }
 
-#[derive(Deserialize, Debug)]
+/// A 'load more comments' item. If `children` is empty, this is a 'continue this thread' link
+/// instead, whose comments can only be loaded from the thread of the parent comment.
+#[derive(Serialize, Deserialize, Debug)]
 pub struct MoreData {
     pub count: u64,
     pub parent_id: String,
     pub children: Vec<String>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct JSONWrapper<T> {
     pub json: JSONInner<T>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct JSONInner<T> {
     pub errors: Vec<Value>,
     pub data: T
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct CommentThings {
     pub things: Vec<BasicThing<CommentData>>
 }
\ No newline at end of file
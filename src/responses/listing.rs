@@ -1,39 +1,97 @@
 use serde_json::Value;
 pub use serde::Deserialize;
-use crate::responses::BasicThing;
+use serde::Serialize;
+use crate::responses::{BasicThing, RichtextFlair, deserialize_author, deserialize_mod_reports,
+                       deserialize_null_default, deserialize_user_reports};
 use crate::responses::comment::CommentListing;
 
 /// The 'listing' format returned by the Reddit API for post lists.
 pub type Listing = BasicThing<ListingData<SubmissionData>>;
 
+/// A response made of two listings in an array (`[link, second]`): a listing holding the
+/// post itself, followed by a listing whose contents depend on the endpoint. Use the accessors
+/// instead of relying on the order of the array.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ArrayListing<T>(Listing, T);
+
+impl<T> ArrayListing<T> {
+    /// The listing holding the post.
+    pub fn link(&self) -> &Listing {
+        &self.0
+    }
+
+    /// The second listing: the comments for /comments/{id}, or the other posts of the same
+    /// link for /duplicates/{id}.
+    pub fn comments(&self) -> &T {
+        &self.1
+    }
+
+    /// Takes the listing holding the post.
+    pub fn into_link(self) -> Listing {
+        self.0
+    }
+
+    /// Takes the second listing. See `ArrayListing.comments()`.
+    pub fn into_comments(self) -> T {
+        self.1
+    }
+
+    /// Takes both listings as a `(link, second)` pair.
+    pub fn into_parts(self) -> (Listing, T) {
+        (self.0, self.1)
+    }
+}
+
 /// The structure returned by the Reddit API for comments, consisting of the original post
 /// and a listing of the comments.
-pub type CommentResponse = (Listing, CommentListing);
+pub type CommentResponse = ArrayListing<CommentListing>;
+
+/// API response from /duplicates/{id}: the original post and a listing of the other posts of
+/// the same link.
+pub type DuplicatesResponse = ArrayListing<Listing>;
 
 /// API response from /r/subreddit/about
 pub type SubredditAbout = BasicThing<SubredditAboutData>;
 
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct SubredditAboutData {
     pub subscribers: u64,
+    /// This is `null` in subreddit listings, in which case it is 0.
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     pub accounts_active: u64,
+    /// Whether Reddit has randomized `accounts_active` slightly, which it does for small
+    /// numbers of active users.
+    #[serde(default)]
+    pub accounts_active_is_fuzzed: Option<bool>,
     pub subreddit_type: String,
     pub title: String,
     pub url: String,
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     pub wiki_enabled: bool,
     pub over18: bool,
+    /// The short description shown in search results and on the subreddit card.
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     pub public_description: String,
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     pub public_description_html: String,
     pub public_traffic: bool,
     pub name: String,
     pub id: String,
     pub display_name: String,
+    /// The full sidebar text.
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     pub description: String,
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     pub description_html: String,
     pub created: f64,
     pub created_utc: f64,
     pub quarantine: bool,
+    /// The warning shown before entering a quarantined subreddit.
+    #[serde(default)]
+    pub quarantine_message: Option<String>,
+    #[serde(default)]
+    pub quarantine_message_html: Option<String>,
     pub submission_type: String,
     pub lang: String,
     pub submit_text: String,
@@ -41,11 +99,150 @@ pub struct SubredditAboutData {
     pub submit_text_label: Option<String>,
     pub submit_link_label: Option<String>,
     pub comment_score_hide_mins: u64,
+    // The fields below are not returned for every subreddit (e.g. in some listings or for
+    // private subreddits), so they are optional.
+    #[serde(default)]
+    pub header_title: Option<String>,
+    #[serde(default)]
+    pub header_img: Option<String>,
+    /// The `[width, height]` of the header image.
+    #[serde(default)]
+    pub header_size: Option<Vec<u32>>,
+    #[serde(default)]
+    pub icon_img: Option<String>,
+    /// The `[width, height]` of the icon image.
+    #[serde(default)]
+    pub icon_size: Option<Vec<u32>>,
+    /// The icon set in the redesign, which may differ from `icon_img`.
+    #[serde(default)]
+    pub community_icon: Option<String>,
+    #[serde(default)]
+    pub banner_img: Option<String>,
+    /// The `[width, height]` of the banner image.
+    #[serde(default)]
+    pub banner_size: Option<Vec<u32>>,
+    #[serde(default)]
+    pub banner_background_image: Option<String>,
+    #[serde(default)]
+    pub mobile_banner_image: Option<String>,
+    #[serde(default)]
+    pub primary_color: Option<String>,
+    #[serde(default)]
+    pub key_color: Option<String>,
+    #[serde(default)]
+    pub allow_images: Option<bool>,
+    #[serde(default)]
+    pub allow_videos: Option<bool>,
+    #[serde(default)]
+    pub allow_videogifs: Option<bool>,
+    #[serde(default)]
+    pub allow_galleries: Option<bool>,
+    #[serde(default)]
+    pub allow_polls: Option<bool>,
+    /// Whether the subreddit can be recommended to users who are not subscribed.
+    #[serde(default)]
+    pub allow_discovery: Option<bool>,
+    #[serde(default)]
+    pub spoilers_enabled: Option<bool>,
+    #[serde(default)]
+    pub emojis_enabled: Option<bool>,
+    #[serde(default)]
+    pub link_flair_enabled: Option<bool>,
+    #[serde(default)]
+    pub user_flair_enabled_in_sr: Option<bool>,
+    /// Whether the logged-in user can choose their own user flair.
+    #[serde(default)]
+    pub can_assign_user_flair: Option<bool>,
+    /// Whether the logged-in user can choose the flair of their posts.
+    #[serde(default)]
+    pub can_assign_link_flair: Option<bool>,
+    #[serde(default)]
+    pub free_form_reports: Option<bool>,
+    #[serde(default)]
+    pub restrict_posting: Option<bool>,
+    #[serde(default)]
+    pub restrict_commenting: Option<bool>,
+    #[serde(default)]
+    pub hide_ads: Option<bool>,
+    /// Whether the subreddit is shown on the front page of logged-out users.
+    #[serde(default)]
+    pub default_set: Option<bool>,
+    #[serde(default)]
+    pub advertiser_category: Option<String>,
+    // The relationship of the logged-in user with the subreddit. These are `null` when
+    // browsing anonymously.
+    #[serde(default)]
+    pub user_is_banned: Option<bool>,
+    #[serde(default)]
+    pub user_is_subscriber: Option<bool>,
+    #[serde(default)]
+    pub user_is_moderator: Option<bool>,
+    #[serde(default)]
+    pub user_is_contributor: Option<bool>,
     // CSS fields omitted
 }
 
+/// The 'listing' format returned by the Reddit API for subreddit lists.
+pub type SubredditListing = BasicThing<ListingData<SubredditAboutData>>;
+
+/// API response from /r/subreddit/about/edit
+pub type SubredditSettingsResponse = BasicThing<SubredditSettings>;
+
+/// The configuration of a subreddit, as shown on the moderator settings page. Fetch these with
+/// `Subreddit.settings()`, change the fields you need and send them back with
+/// `Subreddit.update_settings()`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubredditSettings {
+    /// The full ID of the subreddit, **including the leading `t5_`**.
+    pub subreddit_id: String,
+    /// The title shown in the browser tab.
+    pub title: String,
+    /// The short description shown in search results and to users who cannot access the
+    /// subreddit.
+    #[serde(default)]
+    pub public_description: String,
+    /// The sidebar text, in **Markdown** format.
+    #[serde(default)]
+    pub description: String,
+    /// The text shown on the submission page, in **Markdown** format.
+    #[serde(default)]
+    pub submit_text: String,
+    /// The type of subreddit: `public`, `private`, `restricted`, `gold_restricted` or
+    /// `archived`.
+    pub subreddit_type: String,
+    /// The types of posts allowed: `any`, `link` or `self`.
+    #[serde(rename = "content_options")]
+    pub submission_type: String,
+    /// The primary language of the subreddit, e.g. `en`.
+    #[serde(rename = "language", default)]
+    pub lang: String,
+    /// `true` if the subreddit is marked NSFW (over 18).
+    #[serde(default)]
+    pub over_18: bool,
+    /// `true` if image posts are allowed.
+    #[serde(default)]
+    pub allow_images: bool,
+    /// `true` if video posts are allowed.
+    #[serde(default)]
+    pub allow_videos: bool,
+    /// `true` if posts can be marked as spoilers.
+    #[serde(default)]
+    pub spoilers_enabled: bool,
+    /// `true` if thumbnails are shown for link posts.
+    #[serde(default)]
+    pub show_media: bool,
+    /// Who may edit the wiki: `disabled`, `modonly` or `anyone`.
+    #[serde(default)]
+    pub wikimode: String,
+    /// The other settings, such as the spam filter strength (`spam_links`), the wiki edit limits
+    /// (`wiki_edit_karma`) or `suggested_comment_sort`. These are kept so that
+    /// `Subreddit.update_settings()` can send them back unchanged.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, Value>,
+}
+
 /// The contents of a call to a 'listing' endpoint.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct ListingData<T> {
     /// A modhash (essentially a CSRF token) generated for this request. This is generally
     /// not required for any use-case, but is provided nevertheless.
@@ -55,19 +252,25 @@ pub struct ListingData<T> {
     pub children: Vec<BasicThing<T>>,
 }
 
-/// API response from r/{subreddit}/about/contributors
-#[derive(Deserialize, Debug)]
+/// API response from r/{subreddit}/about/contributors and r/{subreddit}/about/moderators
+pub type UserList = BasicThing<UserListing>;
+
+/// The contents of a user listing, such as the approved contributors of a subreddit.
+#[derive(Serialize, Deserialize, Debug)]
 pub struct UserListing {
     /// A modhash (essentially a CSRF token) generated for this request. This is generally
     /// not required for any use-case, but is provided nevertheless.
     pub modhash: Option<String>,
     pub before: Option<String>,
     pub after: Option<String>,
+    /// The number of users in this page of the listing.
+    #[serde(default)]
+    pub dist: Option<u64>,
     pub children: Vec<UserListingData>,
 }
 
 /// Represents all types of link posts and self posts on Reddit.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct SubmissionData {
     /// The domain of the link (if link post) or self.subreddit (if self post).
     /// Domains do not include a protocol, e.g. `i.redd.it` or `self.learnprogramming`
@@ -96,22 +299,44 @@ pub struct SubmissionData {
     /// - qa
     /// - confidence
     pub suggested_sort: Option<String>,
-    // skipped user_reports and secure_media
+    // skipped secure_media
+    /// The reports made by users as `(reason, count)` pairs, if the logged-in user is a
+    /// moderator of this subreddit.
+    #[serde(default, deserialize_with = "deserialize_user_reports")]
+    pub user_reports: Vec<(String, u64)>,
     /// If this post is flaired, this set to `Some(FLAIR TEXT)`. Otherwise, it is `None`.
     /// Link flairs **can** be empty strings.
     pub link_flair_text: Option<String>,
+    /// The background color of the flair as a hex code (e.g. `#ff4500`). Empty or `None` if the
+    /// flair uses the default color.
+    pub link_flair_background_color: Option<String>,
+    /// The color of the flair text, either `dark` or `light`.
+    pub link_flair_text_color: Option<String>,
+    /// The parts of the flair if it is a richtext flair. Empty for plain text flairs.
+    #[serde(default)]
+    pub link_flair_richtext: Vec<RichtextFlair>,
     /// The ID of the post in base-36 form, as used in Reddit's links.
     pub id: String,
     // skipped from_kind
     /// The amount of times that a user has been gilded (gifted Reddit Gold).
     pub gilded: u64,
+    /// The total number of awards given to the submission.
+    #[serde(default)]
+    pub total_awards_received: u32,
+    /// The number of times that the submission has been crossposted.
+    #[serde(default)]
+    pub num_crossposts: u64,
+    /// The full name of the original submission, if this is a crosspost.
+    pub crosspost_parent: Option<String>,
     /// This is `true` if Reddit has archived the submission (usually done after 6 months).
     /// Archived submissions cannot be voted or commented upon.
     pub archived: bool,
     /// This is `true` if the logged-in user has already followed this link, otherwise `false`.
     pub clicked: bool,
     // skipped report_reasons
-    /// The name of the author of the submission (not including the leading `/u/`)
+    /// The name of the author of the submission (not including the leading `/u/`). This is
+    /// `[deleted]` if the account has been deleted.
+    #[serde(deserialize_with = "deserialize_author")]
     pub author: String,
     // skipped media
     /// The overall points score of this post, as shown on the upvote counter. This is the
@@ -153,7 +378,9 @@ pub struct SubmissionData {
     /// The reason for the post removal, if you are a moderator **and** this post has been
     /// removed.
     pub removal_reason: Option<String>,
-    // TODO: skipped post_hint
+    /// A hint of the type of content in this post, such as `self`, `link` or `image`. This is
+    /// not always present.
+    pub post_hint: Option<String>,
     /// This is `true` if this submission is stickied (an 'annoucement' thread)
     pub stickied: bool,
     // TODO: skipped from
@@ -195,23 +422,81 @@ pub struct SubmissionData {
     /// - Some("admin") - [A]
     /// - Some("special") - other special 'distinguishes' e.g. [Δ]
     pub distinguished: Option<String>,
-    // TODO: skipped mod_reports
+    /// The reports made by moderators as `(reason, moderator)` pairs, if the logged-in user is a
+    /// moderator of this subreddit.
+    #[serde(default, deserialize_with = "deserialize_mod_reports")]
+    pub mod_reports: Vec<(String, String)>,
     /// This is `true` if the user has visited this link.
     pub visited: bool,
     /// The number of reports, if the user is a moderator of this subreddit.
     pub num_reports: Option<u64>,
+    /// The UTC start timestamp of the event, if this is an event post (such as an AMA).
+    pub event_start: Option<f64>,
+    /// The UTC end timestamp of the event, if this is an event post and an end time is set.
+    pub event_end: Option<f64>,
+    /// This is `Some(true)` if this is an event post and the event is currently running.
+    pub event_is_live: Option<bool>,
+    /// This is `true` if contest mode is enabled, which shows the comments in a random order
+    /// and hides their scores.
+    #[serde(default)]
+    pub contest_mode: bool,
+    /// This is `true` if the author receives inbox notifications for replies.
+    #[serde(default)]
+    pub send_replies: bool,
+    /// This is `true` if the author has pinned the post to their profile.
+    #[serde(default)]
+    pub pinned: bool,
+    /// This is `true` if the post is marked as original content (OC).
+    #[serde(default)]
+    pub is_original_content: bool,
+    /// This is `true` if the post is marked as a meta post about the subreddit.
+    #[serde(default)]
+    pub is_meta: bool,
+    /// The full name of the author (`t2_...`), or `None` if the account has been deleted.
+    #[serde(default)]
+    pub author_fullname: Option<String>,
+    /// This is `true` if the author has Reddit Premium.
+    #[serde(default)]
+    pub author_premium: bool,
+    /// This is `true` if the logged-in user has blocked the author.
+    #[serde(default)]
+    pub author_is_blocked: bool,
+    /// The categories that the author chose for the post, if any.
+    #[serde(default)]
+    pub post_categories: Option<Vec<String>>,
+    /// Tags that Reddit uses to change how the post is shown, e.g. `NSFW` overlays.
+    #[serde(default)]
+    pub treatment_tags: Vec<String>,
+    /// The name of the moderator who removed the post, if the logged-in user is a moderator.
+    #[serde(default)]
+    pub removed_by: Option<String>,
+    /// Why the post was removed, e.g. `moderator`, `author`, `deleted` or
+    /// `automod_filtered`. This is `None` if the post has not been removed.
+    #[serde(default)]
+    pub removed_by_category: Option<String>,
+    /// The type of the subreddit where this was posted: `public`, `restricted`, `private`,
+    /// `archived`, `employees_only`, `gold_only` or `user` (for profile posts).
+    #[serde(default)]
+    pub subreddit_type: String,
 }
 
 /// Represents data responded in a user listing
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct UserListingData {
-    /// Date
-    pub date: i64,
+    /// The UTC timestamp of when the user was added to this listing.
+    pub date: f64,
     /// rel_id
     pub rel_id: String,
     /// The username
     pub name: String,
     /// the id
     pub id: String,
+    /// The moderator's note on a ban or mute, if this is a list of banned or muted users.
+    #[serde(default)]
+    pub note: Option<String>,
+    /// The number of days until a temporary ban ends. This is `null` for permanent bans and
+    /// missing in other user lists.
+    #[serde(default)]
+    pub days_left: Option<u64>,
 }
 
@@ -1,4 +1,5 @@
 pub use serde::Deserialize;
+use serde::Serialize;
 
 use serde_json::Value;
 use crate::responses::BasicThing;
@@ -6,7 +7,7 @@ use crate::responses::listing::ListingData;
 
 pub type MessageListingData = BasicThing<ListingData<MessageData>>;
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct MessageData {
     pub author: Option<String>,
     pub body: String,
@@ -3,15 +3,18 @@ pub mod auth;
 pub mod comment;
 pub mod listing;
 pub mod messages;
+pub mod subreddit;
 pub mod user;
+pub mod wiki;
 pub use serde::Deserialize;
+use serde::Serialize;
 
-
+use serde::Deserializer;
 use serde_json::Value;
 
 /// A base structure that can represent both 'Thing' objects and 'Listing' objects, which both
 /// return a `kind` and `data`.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct BasicThing<T> {
     /// An identifier that specifies the type of object that this is.
     /// The valid kinds are:
@@ -28,30 +31,117 @@ pub struct BasicThing<T> {
     pub data: T,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct ThingList {
     pub things: Vec<BasicThing<Value>>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct FlairSelectorResponse {
     pub current: CurrentFlairResponse,
     pub choices: Vec<FlairChoice>
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FlairChoice {
     pub flair_css_class: String,
     pub flair_template_id: String,
+    /// The plain text of the flair. For richtext flairs, emojis are shown by their short name.
     pub flair_text: String,
     pub flair_position: String,
-    pub flair_text_editable: bool
+    pub flair_text_editable: bool,
+    /// The parts (text and emojis) of the flair, if it is a richtext flair.
+    #[serde(default)]
+    pub richtext: Option<Vec<RichtextFlair>>,
+}
+
+impl FlairChoice {
+    /// Returns `true` if this flair has richtext parts (e.g. emojis).
+    pub fn is_richtext(&self) -> bool {
+        self.richtext.as_ref().is_some_and(|parts| !parts.is_empty())
+    }
+
+    /// Gets the plain text of the flair, which is available for all flairs.
+    pub fn plain_text(&self) -> &str {
+        &self.flair_text
+    }
 }
 
-#[derive(Deserialize, Debug)]
+/// A part of a richtext flair, which mixes text and emojis. Reddit sends these as an array of
+/// objects tagged with `e`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "e")]
+pub enum RichtextFlair {
+    /// A run of plain text.
+    #[serde(rename = "text")]
+    Text {
+        #[serde(rename = "t")]
+        text: String,
+    },
+    /// An emoji, with the URL of its image and its short name (e.g. `:snoo:`).
+    #[serde(rename = "emoji")]
+    Emoji {
+        #[serde(rename = "u")]
+        url: String,
+        a: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
 pub struct CurrentFlairResponse {
     pub flair_css_class: Option<String>,
     pub flair_template_id: Option<String>,
     pub flair_text: Option<String>,
     pub flair_position: Option<String>
 }
+
+/// Deserializes a field that Reddit sometimes sends as `null` (e.g. in listings), using the
+/// default value of the type instead.
+pub fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where D: Deserializer<'de>,
+          T: Default + Deserialize<'de>
+{
+    let value: Option<T> = Deserialize::deserialize(deserializer)?;
+    Ok(value.unwrap_or_default())
+}
+
+/// Deserializes the author of a submission or comment, which is `null` in some listings if the
+/// account has been deleted. This is replaced with `[deleted]`, as shown on the website.
+pub fn deserialize_author<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where D: Deserializer<'de>
+{
+    let author: Option<String> = Deserialize::deserialize(deserializer)?;
+    Ok(author.unwrap_or_else(|| String::from("[deleted]")))
+}
+
+/// Deserializes the `mod_reports` array (`[[reason, moderator], ...]`) into
+/// `(reason, moderator)` pairs.
+pub fn deserialize_mod_reports<'de, D>(deserializer: D) -> Result<Vec<(String, String)>, D::Error>
+    where D: Deserializer<'de>
+{
+    let reports: Option<Vec<Vec<Value>>> = Deserialize::deserialize(deserializer)?;
+    Ok(reports.unwrap_or_default()
+        .into_iter()
+        .map(|report| {
+            let reason = report.first().and_then(Value::as_str).unwrap_or("").to_owned();
+            let moderator = report.get(1).and_then(Value::as_str).unwrap_or("").to_owned();
+            (reason, moderator)
+        })
+        .collect())
+}
+
+/// Deserializes the `user_reports` array (`[[reason, count, ...], ...]`) into `(reason, count)`
+/// pairs. Newer responses append extra snooze information to each report, which is ignored.
+pub fn deserialize_user_reports<'de, D>(deserializer: D) -> Result<Vec<(String, u64)>, D::Error>
+    where D: Deserializer<'de>
+{
+    let reports: Option<Vec<Vec<Value>>> = Deserialize::deserialize(deserializer)?;
+    Ok(reports.unwrap_or_default()
+        .into_iter()
+        .map(|report| {
+            let reason = report.first().and_then(Value::as_str).unwrap_or("").to_owned();
+            let count = report.get(1).and_then(Value::as_u64).unwrap_or(0);
+            (reason, count)
+        })
+        .collect())
+}
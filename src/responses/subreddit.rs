@@ -0,0 +1,125 @@
+pub use serde::Deserialize;
+use serde::Serialize;
+
+use std::collections::HashMap;
+
+use crate::responses::RichtextFlair;
+
+/// API response from /api/v1/subreddit/post_requirements, describing the rules that new posts
+/// in a subreddit must follow.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PostRequirements {
+    /// Whether self posts need a body: `required`, `notAllowed` or `none`.
+    pub body_restriction_policy: Option<String>,
+    /// Link posts to these domains are rejected.
+    #[serde(default)]
+    pub domain_blacklist: Vec<String>,
+    /// If this is not empty, link posts are only accepted for these domains.
+    #[serde(default)]
+    pub domain_whitelist: Vec<String>,
+    /// `true` if every post must be given a flair.
+    #[serde(default)]
+    pub is_flair_required: bool,
+    /// The maximum length of a post title, if the subreddit sets a lower limit than Reddit.
+    pub title_text_max_length: Option<usize>,
+    /// If this is not empty, titles must contain at least one of these strings (ignoring case).
+    #[serde(default)]
+    pub title_required_strings: Vec<String>,
+    /// Titles must not contain any of these strings (ignoring case).
+    #[serde(default)]
+    pub title_blacklisted_strings: Vec<String>,
+}
+
+/// A collection of posts in a subreddit, from the /api/v1/collections endpoints.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CollectionData {
+    /// The UUID of the collection.
+    pub collection_id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Either `TIMELINE` or `GALLERY`.
+    pub display_layout: Option<String>,
+    /// The full name of the subreddit, e.g. `t5_2qh1i`.
+    pub subreddit_id: String,
+    /// The name of the moderator who created the collection.
+    pub author_name: String,
+    /// The full names of the posts in the collection, in order.
+    #[serde(default)]
+    pub link_ids: Vec<String>,
+    pub permalink: String,
+    pub created_at_utc: f64,
+    pub last_update_utc: f64,
+}
+
+/// A flair template of a subreddit, as returned by /r/subreddit/api/flairtemplate_v2 and (in a
+/// list) by /r/subreddit/api/link_flair_v2.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FlairTemplate {
+    /// The ID of the template, which is used to assign the flair.
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub css_class: String,
+    /// Either `dark` or `light`.
+    pub text_color: Option<String>,
+    /// A hex code, or an empty string for the default color.
+    #[serde(default)]
+    pub background_color: String,
+    /// Either `text` or `richtext`.
+    #[serde(rename = "type")]
+    pub flair_type: Option<String>,
+    #[serde(default)]
+    pub mod_only: bool,
+    #[serde(default)]
+    pub text_editable: bool,
+    /// The parts (text and emojis) of the flair, if it is a richtext flair.
+    #[serde(default)]
+    pub richtext: Option<Vec<RichtextFlair>>,
+}
+
+/// API response from /r/subreddit/about/rules
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RulesResponse {
+    pub rules: Vec<Rule>,
+}
+
+/// A rule of a subreddit, as shown in the sidebar and the report menu.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Rule {
+    /// The title of the rule.
+    pub short_name: String,
+    /// The explanation of the rule in **Markdown** format. May be empty.
+    #[serde(default)]
+    pub description: String,
+    /// What the rule applies to: `link`, `comment` or `all`.
+    pub kind: String,
+    /// The reason shown when reporting content for breaking this rule.
+    pub violation_reason: Option<String>,
+    /// The position of the rule, starting at 0.
+    pub priority: u32,
+    pub created_utc: f64,
+}
+
+/// API response from /api/v1/subreddit/emojis/all. The emojis are grouped by the subreddit they
+/// belong to (Reddit's own emojis are under `snoomojis`) and keyed by their name.
+pub type EmojisResponse = HashMap<String, HashMap<String, Emoji>>;
+
+/// A custom emoji that can be used in richtext flairs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Emoji {
+    /// The name of the emoji, as used in flair text (`:name:`).
+    #[serde(default)]
+    pub name: String,
+    /// The URL of the emoji image.
+    pub url: String,
+    /// `true` if only moderators can use this emoji in flairs.
+    #[serde(default)]
+    pub mod_flair_only: bool,
+    /// `true` if this emoji can be used in user flairs.
+    #[serde(default)]
+    pub user_flair_allowed: bool,
+    /// `true` if this emoji can be used in post flairs.
+    #[serde(default)]
+    pub post_flair_allowed: bool,
+}
@@ -3,15 +3,16 @@
 pub type UserAbout = BasicThing<UserAboutData>;
 
 pub use serde::Deserialize;
+use serde::Serialize;
 use crate::responses::BasicThing;
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct UserAboutDataCore {
     pub kind: String,
     pub data: UserAboutData
 
 }
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct UserAboutData {
     pub name: String,
     pub snoovatar_img: Option<String>,
@@ -28,3 +29,32 @@ pub struct UserAboutData {
     pub is_mod: bool,
     pub has_verified_email: bool,
 }
+
+/// API response from /api/v1/me/karma
+pub type KarmaList = BasicThing<Vec<KarmaEntry>>;
+
+/// The karma of the logged-in user in a single subreddit.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KarmaEntry {
+    /// The name of the subreddit (not including `/r/`).
+    pub sr: String,
+    /// The comment karma earned in this subreddit.
+    pub comment_karma: i64,
+    /// The link karma earned in this subreddit.
+    pub link_karma: i64,
+}
+
+/// API response from /api/v1/me/prefs, containing the settings of the logged-in account.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AccountPreferences {
+    /// `true` if NSFW (over 18) content is shown.
+    pub over_18: bool,
+    /// `true` if night mode is enabled.
+    pub nightmode: bool,
+    /// `true` if ads are hidden (Reddit Premium only).
+    pub hide_ads: bool,
+    /// The default sort of comment trees, e.g. `confidence` or `new`.
+    pub default_comment_sort: String,
+    /// The interface language, e.g. `en`.
+    pub lang: String,
+}
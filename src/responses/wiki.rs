@@ -0,0 +1,43 @@
+pub use serde::Deserialize;
+use serde::Serialize;
+use crate::responses::BasicThing;
+
+/// API response from /r/subreddit/wiki/page
+pub type WikiPageResponse = BasicThing<WikiPageData>;
+
+/// The contents of a wiki page and information about its latest revision.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WikiPageData {
+    /// The page text in **Markdown** format.
+    pub content_md: String,
+    /// The page text rendered as HTML.
+    pub content_html: String,
+    /// `true` if the logged-in user is allowed to edit this page.
+    pub may_revise: bool,
+    /// The UTC timestamp of the latest revision, if available.
+    pub revision_date: Option<f64>,
+    /// The ID of the latest revision, if available.
+    pub revision_id: Option<String>,
+}
+
+/// API response from /r/subreddit/wiki/settings/page
+pub type WikiPageSettingsResponse = BasicThing<WikiPageSettingsData>;
+
+/// The permissions of a wiki page.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WikiPageSettingsData {
+    /// Who may edit the page: 0 = anyone allowed by the subreddit wiki settings, 1 = only
+    /// approved wiki contributors, 2 = only moderators.
+    pub permlevel: u8,
+    /// The users who have been allowed to edit this page specifically.
+    #[serde(default)]
+    pub editors: Vec<BasicThing<WikiEditor>>,
+    /// `false` if the page is hidden from the list of wiki pages.
+    pub listed: bool,
+}
+
+/// A user who may edit a wiki page.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WikiEditor {
+    pub name: String,
+}
@@ -0,0 +1,77 @@
+use crate::client::RedditClient;
+use crate::errors::APIError;
+use crate::responses::subreddit::CollectionData;
+
+/// A collection of posts in a subreddit, such as a series of related discussion threads.
+/// Collections are created with `Subreddit.create_collection()` and listed with
+/// `Subreddit.collections()`. Changing a collection requires an OAuth authenticator with the
+/// `modposts` scope, and you must be a moderator of the subreddit.
+/// # Examples
+/// ```rust,no_run
+/// use new_rawr::auth::PasswordAuthenticator;
+/// use new_rawr::client::RedditClient;
+/// use new_rawr::options::CollectionLayout;
+/// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+/// let sub = client.subreddit("new_rawr");
+/// let collection = sub.create_collection("Weekly threads", "All weekly threads",
+///                                        CollectionLayout::Timeline)
+///     .expect("Could not create collection");
+/// collection.add_post("t3_4uule8").expect("Could not add post");
+/// ```
+pub struct Collection<'a> {
+    client: &'a RedditClient,
+    data: CollectionData,
+}
+
+impl<'a> Collection<'a> {
+    /// Internal method. Use `Subreddit.collections()` or `Subreddit.create_collection()`
+    /// instead.
+    pub fn new(client: &'a RedditClient, data: CollectionData) -> Collection<'a> {
+        Collection { client, data }
+    }
+
+    /// The ID (a UUID) of this collection.
+    pub fn id(&self) -> &str {
+        &self.data.collection_id
+    }
+
+    /// The title of this collection.
+    pub fn title(&self) -> &str {
+        &self.data.title
+    }
+
+    /// The description of this collection.
+    pub fn description(&self) -> &str {
+        &self.data.description
+    }
+
+    /// The full names of the posts in this collection, in order. This is not updated by
+    /// `Collection.add_post()` or `Collection.remove_post()`.
+    pub fn post_ids(&self) -> &[String] {
+        &self.data.link_ids
+    }
+
+    /// The link to this collection on Reddit.
+    pub fn permalink(&self) -> &str {
+        &self.data.permalink
+    }
+
+    /// Adds the post with the specified full name (e.g. `t3_4uule8`) to this collection. The
+    /// post must be in the same subreddit as the collection.
+    pub fn add_post(&self, post_fullname: &str) -> Result<(), APIError> {
+        let body = format!("collection_id={}&link_fullname={}", self.id(), post_fullname);
+        self.client.post_success("/api/v1/collections/add_post_to_collection", &body, true)
+    }
+
+    /// Removes the post with the specified full name from this collection.
+    pub fn remove_post(&self, post_fullname: &str) -> Result<(), APIError> {
+        let body = format!("collection_id={}&link_fullname={}", self.id(), post_fullname);
+        self.client.post_success("/api/v1/collections/remove_post_in_collection", &body, true)
+    }
+
+    /// Deletes this collection. The posts in it are not affected.
+    pub fn delete(self) -> Result<(), APIError> {
+        let body = format!("collection_id={}", self.id());
+        self.client.post_success("/api/v1/collections/delete_collection", &body, true)
+    }
+}
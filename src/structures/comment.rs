@@ -8,7 +8,10 @@ use crate::errors::APIError;
 use crate::responses::comment::{CommentData};
 use crate::structures::user::User;
 use crate::structures::subreddit::Subreddit;
+use crate::structures::submission::{LazySubmission, Submission};
 use crate::responses::comment::{NewComment, CommentListing};
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// Structure representing a comment and its associated data (e.g. replies)
 pub struct Comment<'a> {
@@ -17,11 +20,43 @@ pub struct Comment<'a> {
     replies: CommentList<'a>,
 }
 
+impl<'a> PartialEq for Comment<'a> {
+    fn eq(&self, other: &Comment) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl<'a> Eq for Comment<'a> {}
+
+impl<'a> Hash for Comment<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+impl<'a> fmt::Debug for Comment<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Comment")
+            .field("name", &self.data.name)
+            .field("author", &self.data.author)
+            .field("parent_id", &self.data.parent_id)
+            .finish()
+    }
+}
+
 impl<'a> Votable for Comment<'a> {
     fn score(&self) -> i64 {
         self.data.score
     }
 
+    fn displayed_score(&self) -> Option<i64> {
+        if self.score_hidden() {
+            None
+        } else {
+            Some(self.score())
+        }
+    }
+
     fn likes(&self) -> Option<bool> {
         self.data.likes
     }
@@ -84,6 +119,13 @@ impl<'a> Content for Comment<'a> {
         User::new(self.client, &self.data.author)
     }
 
+    fn author_name(&self) -> Option<&str> {
+        match self.data.author.as_str() {
+            "[deleted]" | "[removed]" => None,
+            author => Some(author),
+        }
+    }
+
     fn author_flair_text(&self) -> Option<String> {
         self.data.author_flair_text.to_owned()
     }
@@ -97,8 +139,9 @@ impl<'a> Content for Comment<'a> {
     }
 
     fn delete(self) -> Result<(), APIError> {
-        let body = format!("id={}", self.data.name);
-        self.client.post_success("/api/del", &body, false)
+        // Reddit responds with 200 even if the delete is refused, so check the errors too.
+        let body = format!("api_type=json&id={}", self.data.name);
+        self.client.post_api("/api/del", &body, false).map(|_| ())
     }
 
     fn name(&self) -> &str {
@@ -135,6 +178,9 @@ impl<'a> Commentable<'a> for Comment<'a> {
     }
 
     fn reply(&self, text: &str) -> Result<Comment, APIError> {
+        if self.data.archived {
+            return Err(APIError::Archived);
+        }
         let body = format!("api_type=json&text={}&thing_id={}",
                            self.client.url_escape(text.to_owned()),
                            self.name());
@@ -150,6 +196,19 @@ impl<'a> Commentable<'a> for Comment<'a> {
 }
 
 impl<'a> Comment<'a> {
+    /// This is `true` if the score of this comment is hidden, which some subreddits do for
+    /// new comments. While hidden, `Votable.score()` is meaningless; use
+    /// `Votable.displayed_score()` instead.
+    pub fn score_hidden(&self) -> bool {
+        self.data.score_hidden
+    }
+
+    /// This is `true` if Reddit has archived this comment (usually done 6 months after the post was
+    /// submitted), so it can no longer be replied to or voted on.
+    pub fn archived(&self) -> bool {
+        self.data.archived
+    }
+
     /// Internal method. Use `Submission.replies()` or `Comment.replies()` to get a listing, then
     /// select the desired comment instead.
     pub fn new(client: &RedditClient, data: CommentData) -> Comment {
@@ -176,6 +235,60 @@ impl<'a> Comment<'a> {
         &self.data.parent_id
     }
 
+    /// Returns `true` if this comment is a direct reply to the submission rather than to
+    /// another comment.
+    #[inline]
+    pub fn is_top_level(&self) -> bool {
+        self.data.parent_id.starts_with("t3_")
+    }
+
+    /// Gets how deeply this comment is nested in the comment tree (0 for top-level comments).
+    /// This is `None` for comments that were not fetched as part of a tree, e.g. from
+    /// `User.comments()`; use `Comment.is_top_level()` for those.
+    #[inline]
+    pub fn depth(&self) -> Option<u32> {
+        self.data.depth
+    }
+
+    /// Gets the controversiality of this comment: 1 if it has received a similar number of
+    /// upvotes and downvotes (shown with a dagger on the website), 0 otherwise.
+    #[inline]
+    pub fn controversiality(&self) -> u8 {
+        self.data.controversiality
+    }
+
+    /// Returns `true` if this comment has received a similar number of upvotes and downvotes,
+    /// e.g. because it is part of a heated debate.
+    #[inline]
+    pub fn is_controversial(&self) -> bool {
+        self.controversiality() == 1
+    }
+
+    /// Gets a `LazySubmission` for the submission that this comment was posted on, which can be
+    /// used to fetch the post itself or its other replies.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::traits::Commentable;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let all = client.subreddit("all");
+    /// let post = all.hot(ListingOptions::default()).unwrap().next().unwrap();
+    /// let comment = post.replies().unwrap().next().unwrap();
+    /// let parent = comment.submission().get().expect("Could not fetch submission");
+    /// ```
+    pub fn submission(&self) -> LazySubmission<'a> {
+        LazySubmission::new(self.client, &self.data.link_id)
+    }
+
+    /// Fetches the submission that this comment was posted on. This is a shortcut for
+    /// `comment.submission().get()`, and is useful to get the context of a comment that was
+    /// received on its own (e.g. from a username mention).
+    pub fn get_parent_submission(&self) -> Result<Submission<'a>, APIError> {
+        self.submission().get()
+    }
+
     /// Adds a reply to this comment's reply list. This is an internal method - to make the client
     /// reply to this post, use `Comment.reply(MESSAGE)`.
     pub fn add_reply(&mut self, item: Comment<'a>) {
@@ -199,6 +312,14 @@ impl<'a> Reportable for Comment<'a> {
     fn report_count(&self) -> Option<u64> {
         self.data.num_reports.to_owned()
     }
+
+    fn mod_reports(&self) -> Vec<(String, String)> {
+        self.data.mod_reports.to_owned()
+    }
+
+    fn user_reports(&self) -> Vec<(String, u64)> {
+        self.data.user_reports.to_owned()
+    }
 }
 
 impl<'a> Stickable for Comment<'a> {
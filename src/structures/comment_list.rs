@@ -8,16 +8,19 @@ use crate::client::RedditClient;
 use crate::structures::comment::Comment;
 use crate::responses::BasicThing;
 use crate::responses::listing;
-use crate::responses::comment::{CommentData, MoreData};
+use crate::responses::comment::{CommentData, CommentListing, MoreData};
 use serde_json::{Value, from_value, from_str};
 use std::io::Read;
 use crate::errors::APIError;
+use crate::structures::fullname::Fullname;
+use std::str::FromStr;
 use crate::traits::Content;
 use hyper::Body;
 
 /// A list of comments that can be iterated through. Automatically fetches 'more' links when
 /// necessary until all comments have been consumed, which can lead to pauses while loading
-/// from the API.
+/// from the API. If loading more comments fails, the iterator ends early and the error can be
+/// retrieved with `last_error()`.
 /// # Examples
 /// ```
 /// use new_rawr::client::RedditClient;
@@ -40,6 +43,7 @@ pub struct CommentList<'a> {
     more: Vec<MoreData>,
     link_id: String,
     parent: String,
+    last_error: Option<APIError>,
 }
 
 impl<'a> CommentList<'a> {
@@ -75,6 +79,7 @@ impl<'a> CommentList<'a> {
             comment_hashes: hashes,
             link_id: link_id,
             parent: parent,
+            last_error: None,
         }
     }
 
@@ -87,9 +92,16 @@ impl<'a> CommentList<'a> {
             comments: Vec::new(),
             more: Vec::new(),
             comment_hashes: HashMap::new(),
+            last_error: None,
         }
     }
 
+    /// Returns the error that ended the iteration early, if fetching more comments failed.
+    /// This is `None` while the list is still being iterated or if it was fully exhausted.
+    pub fn last_error(&self) -> Option<&APIError> {
+        self.last_error.as_ref()
+    }
+
     /// Adds a (pre-existing) comment to the reply list. This is an internal method, and does not
     /// actually post a comment, just adds one that has already been fetched.
     pub fn add_reply(&mut self, item: Comment<'a>) {
@@ -97,18 +109,55 @@ impl<'a> CommentList<'a> {
         self.comments.push(item);
     }
 
-    fn fetch_more(&mut self, more_item: MoreData) -> CommentList<'a> {
+    /// The URL of the thread that holds the comments of a 'continue this thread' link, or `None`
+    /// if the item is an ordinary 'load more comments' item. Fails with
+    /// `APIError::InvalidFullname` if the link or parent is not a valid full name.
+    pub(crate) fn continue_thread_url(link_id: &str, more_item: &MoreData)
+                                      -> Result<Option<String>, APIError> {
+        if !more_item.children.is_empty() {
+            return Ok(None);
+        }
+        let parent = Fullname::from_str(&more_item.parent_id)?;
+        if parent.kind() != "t1" {
+            return Ok(None);
+        }
+        let link = Fullname::from_str(link_id)?.expect_kind("t3")?;
+        Ok(Some(format!("/comments/{}/_/{}?raw_json=1", link.id36(), parent.id36())))
+    }
+
+    /// Fetches the replies behind a 'continue this thread' link. The thread starts with the
+    /// parent comment, whose replies are the comments that were left out.
+    fn fetch_thread(&self, url: &str) -> Result<CommentList<'a>, APIError> {
+        let string = self.client.get_json(url, false)?;
+        let thread: listing::CommentResponse = from_str(&string)?;
+        let replies = thread.into_comments().data.children
+            .into_iter()
+            .find(|item| item.kind == "t1")
+            .map(|parent| from_value::<CommentData>(parent.data))
+            .transpose()?
+            .filter(|parent| parent.replies.is_object())
+            .map(|parent| from_value::<CommentListing>(parent.replies))
+            .transpose()?
+            .map(|listing| listing.data.children)
+            .unwrap_or_default();
+        Ok(CommentList::new(self.client, self.link_id.to_owned(), self.parent.to_owned(), replies))
+    }
+
+    fn fetch_more(&mut self, more_item: MoreData) -> Result<CommentList<'a>, APIError> {
+        if let Some(url) = CommentList::continue_thread_url(&self.link_id, &more_item)? {
+            return self.fetch_thread(&url);
+        }
         let params = format!("api_type=json&raw_json=1&link_id={}&children={}",
                              &self.link_id,
                              &more_item.children.join(","));
         let url = "/api/morechildren";
         self.client
             .ensure_authenticated(|| {
-                let request = self.client.post(url, false).body(Body::from(params.clone())).unwrap();
+                let request = self.client.post(url, false)?.body(Body::from(params.clone())).unwrap();
 
                 let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
 
-                let res = runtime.block_on(self.client.client.request(request)).unwrap();
+                let res = runtime.block_on(self.client.send(request))?;
                 if res.status().is_success() {
                     // The "data" attribute is sometimes not present, so we have to unwrap it all
                     // manually
@@ -138,7 +187,6 @@ impl<'a> CommentList<'a> {
                     Err(APIError::HTTPError(res.status()))
                 }
             })
-            .unwrap()
     }
 
     fn merge_more_comments(&mut self, list: CommentList<'a>) {
@@ -193,7 +241,14 @@ impl<'a> Iterator for CommentList<'a> {
                 // XXX: This code is hideous (see the fetch_more etc.) but it does work.
                 // TODO: refactor (carefully!)
                 let more_item = self.more.drain(..1).next().unwrap();
-                let mut new_listing = self.fetch_more(more_item);
+                let mut new_listing = match self.fetch_more(more_item) {
+                    Ok(new_listing) => new_listing,
+                    Err(err) => {
+                        self.more.clear();
+                        self.last_error = Some(err);
+                        return None;
+                    }
+                };
                 self.more.append(&mut new_listing.more);
                 // We've already consumed all of the items, so we can remove the mapping now.
                 self.comment_hashes = HashMap::new();
@@ -270,7 +325,7 @@ impl<'a> Iterator for CommentStream<'a> {
                 let current_iter = CommentList::new(self.client,
                                                     self.link_name.to_owned(),
                                                     self.link_name.to_owned(),
-                                                    req.1.data.children)
+                                                    req.into_comments().data.children)
                     .take(5)
                     .collect::<Vec<Comment>>()
                     .into_iter()
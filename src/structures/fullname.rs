@@ -0,0 +1,74 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use crate::errors::APIError;
+
+/// The full name of a Reddit item, made up of its kind and its base-36 ID (e.g. `t3_4uule8`).
+/// The kinds are listed on `responses::BasicThing`.
+/// # Examples
+/// ```
+/// use new_rawr::structures::fullname::Fullname;
+/// let name: Fullname = "t3_4uule8".parse().unwrap();
+/// assert_eq!(name.kind(), "t3");
+/// assert_eq!(name.id36(), "4uule8");
+/// assert_eq!(name.as_str(), "t3_4uule8");
+/// assert!("4uule8".parse::<Fullname>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fullname {
+    name: String,
+    separator: usize,
+}
+
+impl Fullname {
+    /// Gets the kind of the item, e.g. `t3` for a submission.
+    pub fn kind(&self) -> &str {
+        &self.name[..self.separator]
+    }
+
+    /// Gets the base-36 ID of the item without the kind, as used in links.
+    pub fn id36(&self) -> &str {
+        &self.name[self.separator + 1..]
+    }
+
+    /// Gets the whole full name, e.g. `t3_4uule8`.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns an `APIError::InvalidFullname` unless this is the full name of the specified
+    /// kind of item.
+    pub fn expect_kind(self, kind: &str) -> Result<Fullname, APIError> {
+        if self.kind() == kind {
+            Ok(self)
+        } else {
+            Err(APIError::InvalidFullname(self.name))
+        }
+    }
+}
+
+impl FromStr for Fullname {
+    type Err = APIError;
+    fn from_str(s: &str) -> Result<Fullname, APIError> {
+        let invalid = || APIError::InvalidFullname(s.to_owned());
+        let separator = s.find('_').ok_or_else(invalid)?;
+        let (kind, id) = (&s[..separator], &s[separator + 1..]);
+        let valid_kind = kind.len() > 1 && kind.starts_with('t') &&
+                         kind[1..].chars().all(|c| c.is_ascii_digit());
+        let valid_id = !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric());
+        if valid_kind && valid_id {
+            Ok(Fullname {
+                name: s.to_owned(),
+                separator,
+            })
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+impl Display for Fullname {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.name)
+    }
+}
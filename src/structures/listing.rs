@@ -3,9 +3,15 @@ use std::collections::VecDeque;
 use std::thread;
 use std::time::Duration;
 
-use crate::responses::listing;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, from_value};
+
+use crate::responses::{listing, BasicThing};
+use crate::responses::comment::CommentData;
 use crate::client::RedditClient;
+use crate::structures::comment::Comment;
 use crate::structures::submission::Submission;
+use crate::structures::subreddit::SubredditAbout;
 use crate::traits::{Content, PageListing};
 use crate::errors::APIError;
 
@@ -41,7 +47,9 @@ use crate::errors::APIError;
 /// use new_rawr::options::ListingAnchor;
 /// ListingOptions {
 ///     batch: 100,
-///     anchor: ListingAnchor::None
+///     anchor: ListingAnchor::None,
+///     exclude_sticky: false,
+///     include_nsfw: false,
 /// };
 /// ```
 ///
@@ -49,9 +57,9 @@ use crate::errors::APIError;
 /// response by using a smaller batch size (and the Reddit admins would love it if you didn't
 /// waste bandwidth!)
 pub struct Listing<'a> {
-    client: &'a RedditClient,
-    query_stem: String,
-    data: listing::ListingData<listing::SubmissionData>,
+    pages: Pager<'a, listing::SubmissionData>,
+    exclude_sticky: bool,
+    remaining: Option<usize>,
 }
 
 impl<'a> Listing<'a> {
@@ -61,62 +69,500 @@ impl<'a> Listing<'a> {
                data: listing::ListingData<listing::SubmissionData>)
                -> Listing {
         Listing {
-            client: client,
-            query_stem: query_stem,
-            data: data,
+            pages: Pager::new(client, query_stem, data),
+            exclude_sticky: false,
+            remaining: None,
+        }
+    }
+
+    /// Returns the error that ended the iteration early, if fetching the next page failed.
+    /// This is `None` while the listing is still being iterated or if it was fully exhausted.
+    pub fn last_error(&self) -> Option<&APIError> {
+        self.pages.last_error.as_ref()
+    }
+
+    /// Makes this listing skip any stickied posts. Use `ListingOptions::no_stickies()` when
+    /// requesting a feed instead of calling this directly.
+    pub fn without_stickies(mut self) -> Listing<'a> {
+        self.exclude_sticky = true;
+        self
+    }
+
+    /// Stops the listing after `n` posts. Unlike `Iterator.take()`, this also reduces the size
+    /// of the last page that is requested, so no more posts are fetched than needed.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("all");
+    /// let new = sub.new(ListingOptions::default()).expect("Could not fetch posts").limit(30);
+    /// // Requests 25 posts, then only 5 more.
+    /// assert_eq!(new.count(), 30);
+    /// ```
+    pub fn limit(mut self, n: usize) -> Listing<'a> {
+        // The current page is kept whole: skipped stickies do not count towards the limit, and
+        // the next page would start after the last post of this page anyway.
+        self.remaining = Some(n);
+        self
+    }
+
+    /// Skips the posts for which `predicate` returns `false`. Like `Iterator.filter()`, but the
+    /// result is still a listing type that can be passed around by name.
+    pub fn filtered<P>(self, predicate: P) -> FilteredListing<'a, P>
+        where P: Fn(&Submission<'a>) -> bool
+    {
+        FilteredListing {
+            listing: self,
+            predicate,
+        }
+    }
+
+    /// Skips the posts that have received fewer than `min` awards.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("all");
+    /// let hot = sub.hot(ListingOptions::default()).expect("Could not fetch posts");
+    /// for post in hot.filter_by_min_awards(5).take(10) {
+    ///     println!("{} ({} awards)", post.title(), post.award_count());
+    /// }
+    /// ```
+    pub fn filter_by_min_awards(self, min: u32)
+                                -> FilteredListing<'a, impl Fn(&Submission<'a>) -> bool> {
+        self.filtered(move |post| post.award_count() >= min)
+    }
+
+    /// Turns this listing around, so it pages backwards using the `before` tokens instead of
+    /// forwards. The posts of the current page are yielded in reverse order, followed by the
+    /// pages before it, until the start of the listing is reached.
+    ///
+    /// This is mostly useful for anchored listings, e.g. to walk from a known post in the new
+    /// queue towards the newest posts, oldest first.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{ListingAnchor, ListingOptions};
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("learnprogramming");
+    /// let opts = ListingOptions {
+    ///     batch: 25,
+    ///     anchor: ListingAnchor::After(String::from("t3_4uule8")),
+    ///     exclude_sticky: false,
+    ///     include_nsfw: false,
+    /// };
+    /// let newer = sub.new(opts).expect("Could not fetch posts").reversed();
+    /// for post in newer.take(50) {
+    ///     println!("{}", post.title());
+    /// }
+    /// ```
+    pub fn reversed(self) -> ReversedListing<'a> {
+        ReversedListing {
+            pages: self.pages,
+            exclude_sticky: self.exclude_sticky,
         }
     }
 }
 
 impl<'a> PageListing for Listing<'a> {
     fn before(&self) -> Option<String> {
-        self.data.before.to_owned()
+        self.pages.data.before.to_owned()
     }
 
     fn after(&self) -> Option<String> {
-        self.data.after.to_owned()
+        self.pages.data.after.to_owned()
     }
 
     fn modhash(&self) -> Option<String> {
-        self.data.modhash.to_owned()
+        self.pages.data.modhash.to_owned()
     }
 }
 
+#[cfg(test)]
 impl<'a> Listing<'a> {
-    fn fetch_after(&mut self) -> Result<Listing<'a>, APIError> {
-        match self.after() {
-            Some(after_id) => {
-                let url = format!("{}&after={}", self.query_stem, after_id);
-                let string = self.client
-                    .get_json(&url, false).unwrap();
-                let string :listing::Listing= serde_json::from_str(&*string).unwrap();
-                Ok(Listing::new(self.client, self.query_stem.to_owned(), string.data))
+    /// The URL of the next page. Reddit expects the number of items seen so far in `count`,
+    /// otherwise items can be skipped or repeated when the listing changes while paging.
+    pub(crate) fn next_page_url(&self) -> Option<String> {
+        self.pages.next_page_url(self.remaining)
+    }
 
-            }
-            None => Err(APIError::ExhaustedListing),
-        }
+    /// Adds a freshly fetched page to the end of this listing.
+    pub(crate) fn push_page(&mut self, page: listing::ListingData<listing::SubmissionData>) {
+        self.pages.push_page(page);
     }
 }
 
 impl<'a> Iterator for Listing<'a> {
     type Item = Submission<'a>;
     fn next(&mut self) -> Option<Submission<'a>> {
-        if self.data.children.is_empty() {
-            if self.after().is_none() {
-                None
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let exclude_sticky = self.exclude_sticky;
+        let remaining = &mut self.remaining;
+        self.pages.next_item(*remaining, |client, child| {
+            if exclude_sticky && child.data.stickied {
+                return Ok(None);
+            }
+            if let Some(ref mut remaining) = *remaining {
+                *remaining -= 1;
+            }
+            Ok(Some(Submission::new(client, child.data)))
+        })
+    }
+}
+
+/// A `Listing` that only yields the posts matching a predicate. See `Listing.filtered()`.
+pub struct FilteredListing<'a, P> {
+    listing: Listing<'a>,
+    predicate: P,
+}
+
+impl<'a, P> Iterator for FilteredListing<'a, P>
+    where P: Fn(&Submission<'a>) -> bool
+{
+    type Item = Submission<'a>;
+    fn next(&mut self) -> Option<Submission<'a>> {
+        let predicate = &self.predicate;
+        self.listing.find(|post| predicate(post))
+    }
+}
+
+/// Replaces the `limit` parameter of a listing URL with a smaller value, so that a page does
+/// not contain more than `max` items.
+fn with_limit(url: &str, max: usize) -> String {
+    let (path, query) = match url.find('?') {
+        Some(index) => (&url[..index], &url[index + 1..]),
+        None => return url.to_owned(),
+    };
+    let params = query.split('&')
+        .map(|param| match param.strip_prefix("limit=").map(str::parse::<usize>) {
+            Some(Ok(limit)) if limit > max => format!("limit={}", max),
+            _ => param.to_owned(),
+        })
+        .collect::<Vec<_>>();
+    format!("{}?{}", path, params.join("&"))
+}
+
+/// The paging state shared by the listing types: the current page, the URL stem that the other
+/// pages are requested with and the number of items fetched so far, which Reddit expects in
+/// `count`. If a page cannot be fetched, paging stops and the error is kept in `last_error`.
+struct Pager<'a, T> {
+    client: &'a RedditClient,
+    query_stem: String,
+    data: listing::ListingData<T>,
+    count: usize,
+    last_error: Option<APIError>,
+}
+
+impl<'a, T> Pager<'a, T>
+    where T: DeserializeOwned
+{
+    fn new(client: &'a RedditClient,
+           query_stem: String,
+           data: listing::ListingData<T>)
+           -> Pager<'a, T> {
+        let count = data.children.len();
+        Pager {
+            client,
+            query_stem,
+            data,
+            count,
+            last_error: None,
+        }
+    }
+
+    /// The URL of the next page, which holds at most `max` items if given.
+    fn next_page_url(&self, max: Option<usize>) -> Option<String> {
+        self.data.after.as_ref().map(|after_id| {
+            let stem = match max {
+                Some(max) => with_limit(&self.query_stem, max),
+                None => self.query_stem.to_owned(),
+            };
+            format!("{}&count={}&after={}", stem, self.count, after_id)
+        })
+    }
+
+    /// The URL of the previous page, for paging backwards with the `before` token.
+    fn prev_page_url(&self) -> Option<String> {
+        self.data.before.as_ref().map(|before_id| {
+            format!("{}&count={}&before={}", self.query_stem, self.count, before_id)
+        })
+    }
+
+    fn push_page(&mut self, mut page: listing::ListingData<T>) {
+        self.count += page.children.len();
+        self.data.children.append(&mut page.children);
+        self.data.after = page.after;
+    }
+
+    fn push_page_before(&mut self, mut page: listing::ListingData<T>) {
+        self.count += page.children.len();
+        page.children.append(&mut self.data.children);
+        self.data.children = page.children;
+        self.data.before = page.before;
+    }
+
+    fn fetch(&self, url: &str) -> Result<listing::ListingData<T>, APIError> {
+        let string = self.client.get_json(url, false)?;
+        let page: BasicThing<listing::ListingData<T>> = serde_json::from_str(&string)?;
+        Ok(page.data)
+    }
+
+    /// Stops paging, keeping the error that caused it.
+    fn fail(&mut self, err: APIError) {
+        self.data.children.clear();
+        self.data.before = None;
+        self.data.after = None;
+        self.last_error = Some(err);
+    }
+
+    /// Builds the next item from the next child with `make`, fetching the next page (of at most
+    /// `max` items) once the current one is used up. `make` can return `Ok(None)` to skip a
+    /// child, or an error to stop paging.
+    fn next_item<I, F>(&mut self, max: Option<usize>, make: F) -> Option<I>
+        where F: FnMut(&'a RedditClient, BasicThing<T>) -> Result<Option<I>, APIError>
+    {
+        self.take_item(false, max, make)
+    }
+
+    /// Like `next_item()`, but takes the children from the end of the page and fetches the
+    /// previous page once the current one is used up.
+    fn prev_item<I, F>(&mut self, make: F) -> Option<I>
+        where F: FnMut(&'a RedditClient, BasicThing<T>) -> Result<Option<I>, APIError>
+    {
+        self.take_item(true, None, make)
+    }
+
+    fn take_item<I, F>(&mut self, backwards: bool, max: Option<usize>, mut make: F) -> Option<I>
+        where F: FnMut(&'a RedditClient, BasicThing<T>) -> Result<Option<I>, APIError>
+    {
+        loop {
+            if self.data.children.is_empty() {
+                let url = if backwards {
+                    self.prev_page_url()?
+                } else {
+                    self.next_page_url(max)?
+                };
+                match self.fetch(&url) {
+                    Ok(page) if backwards => self.push_page_before(page),
+                    Ok(page) => self.push_page(page),
+                    Err(err) => {
+                        self.fail(err);
+                        return None;
+                    }
+                }
+                continue;
+            }
+            let child = if backwards {
+                self.data.children.pop().unwrap()
             } else {
-                let mut new_listing = self.fetch_after().expect("After does not exist!");
-                self.data.children.append(&mut new_listing.data.children);
-                self.data.after = new_listing.data.after;
-                self.next()
+                self.data.children.remove(0)
+            };
+            match make(self.client, child) {
+                Ok(Some(item)) => return Some(item),
+                Ok(None) => {}
+                Err(err) => {
+                    self.fail(err);
+                    return None;
+                }
             }
-        } else {
-            let child = self.data.children.drain(..1).next().unwrap();
-            Some(Submission::new(self.client, child.data))
         }
     }
 }
 
+/// A listing of posts that is iterated backwards, created with `Listing.reversed()`.
+pub struct ReversedListing<'a> {
+    pages: Pager<'a, listing::SubmissionData>,
+    exclude_sticky: bool,
+}
+
+impl<'a> ReversedListing<'a> {
+    /// Returns the error that ended the iteration early, if fetching the previous page failed.
+    /// This is `None` while the listing is still being iterated or if it was fully exhausted.
+    pub fn last_error(&self) -> Option<&APIError> {
+        self.pages.last_error.as_ref()
+    }
+
+    /// The URL of the previous page, which also carries the running `count`.
+    #[cfg(test)]
+    pub(crate) fn prev_page_url(&self) -> Option<String> {
+        self.pages.prev_page_url()
+    }
+}
+
+impl<'a> PageListing for ReversedListing<'a> {
+    fn before(&self) -> Option<String> {
+        self.pages.data.before.to_owned()
+    }
+
+    fn after(&self) -> Option<String> {
+        self.pages.data.after.to_owned()
+    }
+
+    fn modhash(&self) -> Option<String> {
+        self.pages.data.modhash.to_owned()
+    }
+}
+
+impl<'a> Iterator for ReversedListing<'a> {
+    type Item = Submission<'a>;
+    fn next(&mut self) -> Option<Submission<'a>> {
+        let exclude_sticky = self.exclude_sticky;
+        self.pages.prev_item(|client, child| {
+            if exclude_sticky && child.data.stickied {
+                return Ok(None);
+            }
+            Ok(Some(Submission::new(client, child.data)))
+        })
+    }
+}
+
+/// A paginated listing of subreddits, such as the popular subreddits. It is paginated in the
+/// same way as `Listing`, but yields the `SubredditAbout` data of each subreddit.
+/// # Examples
+/// ```rust,no_run
+/// use new_rawr::client::RedditClient;
+/// use new_rawr::options::ListingOptions;
+/// use new_rawr::auth::AnonymousAuthenticator;
+/// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+/// let popular = client.popular_subreddits(ListingOptions::default())
+///     .expect("Could not fetch subreddits");
+/// for sub in popular.take(10) {
+///     println!("/r/{}: {} subscribers", sub.display_name(), sub.subscribers());
+/// }
+/// ```
+pub struct SubredditListing<'a> {
+    pages: Pager<'a, listing::SubredditAboutData>,
+}
+
+impl<'a> SubredditListing<'a> {
+    /// Internal method. Use other functions that return subreddit listings, such as
+    /// `RedditClient.popular_subreddits()`.
+    pub fn new(client: &'a RedditClient,
+               query_stem: String,
+               data: listing::ListingData<listing::SubredditAboutData>)
+               -> SubredditListing<'a> {
+        SubredditListing {
+            pages: Pager::new(client, query_stem, data),
+        }
+    }
+
+    /// Returns the error that ended the iteration early, if fetching the next page failed.
+    /// This is `None` while the listing is still being iterated or if it was fully exhausted.
+    pub fn last_error(&self) -> Option<&APIError> {
+        self.pages.last_error.as_ref()
+    }
+}
+
+impl<'a> PageListing for SubredditListing<'a> {
+    fn before(&self) -> Option<String> {
+        self.pages.data.before.to_owned()
+    }
+
+    fn after(&self) -> Option<String> {
+        self.pages.data.after.to_owned()
+    }
+
+    fn modhash(&self) -> Option<String> {
+        self.pages.data.modhash.to_owned()
+    }
+}
+
+impl<'a> Iterator for SubredditListing<'a> {
+    type Item = SubredditAbout;
+    fn next(&mut self) -> Option<SubredditAbout> {
+        self.pages.next_item(None, |_, child| Ok(Some(SubredditAbout::new(child.data))))
+    }
+}
+
+/// An item from a `MixedListing`, which can be either a submission or a comment.
+pub enum MixedItem<'a> {
+    /// A link post or self post (kind `t3`).
+    Submission(Box<Submission<'a>>),
+    /// A comment (kind `t1`).
+    Comment(Box<Comment<'a>>),
+}
+
+/// A paginated listing that contains both submissions and comments, such as a user's overview
+/// or saved items. It is paginated in the same way as `Listing`, but yields `MixedItem`s.
+/// # Examples
+/// ```rust,no_run
+/// use new_rawr::client::RedditClient;
+/// use new_rawr::auth::AnonymousAuthenticator;
+/// use new_rawr::structures::listing::MixedItem;
+/// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+/// let user = client.user("Aurora0001");
+/// for item in user.overview().expect("Could not fetch overview").take(10) {
+///     match item {
+///         MixedItem::Submission(post) => println!("Post: {}", post.title()),
+///         MixedItem::Comment(_) => println!("Comment"),
+///     }
+/// }
+/// ```
+pub struct MixedListing<'a> {
+    pages: Pager<'a, Value>,
+}
+
+impl<'a> MixedListing<'a> {
+    /// Internal method. Use other functions that return mixed listings, such as
+    /// `User.overview()`.
+    pub fn new(client: &'a RedditClient,
+               query_stem: String,
+               data: listing::ListingData<Value>)
+               -> MixedListing<'a> {
+        MixedListing {
+            pages: Pager::new(client, query_stem, data),
+        }
+    }
+
+    /// Returns the error that ended the iteration early, if fetching the next page failed or an
+    /// item could not be read. This is `None` while the listing is still being iterated or if it
+    /// was fully exhausted.
+    pub fn last_error(&self) -> Option<&APIError> {
+        self.pages.last_error.as_ref()
+    }
+}
+
+impl<'a> PageListing for MixedListing<'a> {
+    fn before(&self) -> Option<String> {
+        self.pages.data.before.to_owned()
+    }
+
+    fn after(&self) -> Option<String> {
+        self.pages.data.after.to_owned()
+    }
+
+    fn modhash(&self) -> Option<String> {
+        self.pages.data.modhash.to_owned()
+    }
+}
+
+impl<'a> Iterator for MixedListing<'a> {
+    type Item = MixedItem<'a>;
+    fn next(&mut self) -> Option<MixedItem<'a>> {
+        self.pages.next_item(None, |client, child| {
+            match child.kind.as_str() {
+                "t1" => {
+                    let data = from_value::<CommentData>(child.data)?;
+                    Ok(Some(MixedItem::Comment(Box::new(Comment::new(client, data)))))
+                }
+                "t3" => {
+                    let data = from_value::<listing::SubmissionData>(child.data)?;
+                    Ok(Some(MixedItem::Submission(Box::new(Submission::new(client, data)))))
+                }
+                // Other kinds (e.g. messages) are not expected in these listings, so skip them.
+                _ => Ok(None),
+            }
+        })
+    }
+}
+
 /// An infinite stream of posts which updates as posts are received. Stores a list of seen posts
 /// so that each post is only seen once.
 ///
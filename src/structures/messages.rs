@@ -42,6 +42,22 @@ impl<'a> Message<'a> {
         let body = format!("id={}", self.name());
         self.client.post_success("/api/read_message", &body, false)
     }
+
+    /// Blocks the author of this message so they cannot send any further messages. This is the
+    /// same as `message.author().block()`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::ListingOptions;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// for message in client.messages().unread(ListingOptions::default()).unwrap() {
+    ///     message.block_author().expect("Could not block author");
+    /// }
+    /// ```
+    pub fn block_author(&self) -> Result<(), APIError> {
+        self.author().block()
+    }
 }
 
 impl<'a> Commentable<'a> for Message<'a> {
@@ -83,6 +99,15 @@ impl<'a> Content for Message<'a> {
         User::new(self.client, &author)
     }
 
+    fn author_name(&self) -> Option<&str> {
+        match self.data.author.as_deref() {
+            Some("[deleted]") => None,
+            Some(author) => Some(author),
+            // Messages without an author are sent by Reddit itself, like in `author()`.
+            None => Some("reddit"),
+        }
+    }
+
     fn author_flair_text(&self) -> Option<String> {
         None
     }
@@ -97,8 +122,9 @@ impl<'a> Content for Message<'a> {
     }
 
     fn delete(self) -> Result<(), APIError> {
-        let body = format!("id={}", self.data.name);
-        self.client.post_success("/api/del_msg", &body, false)
+        // Reddit responds with 200 even if the delete is refused, so check the errors too.
+        let body = format!("api_type=json&id={}", self.data.name);
+        self.client.post_api("/api/del_msg", &body, false).map(|_| ())
     }
 
     fn name(&self) -> &str {
@@ -202,6 +228,47 @@ impl<'a> MessageInterface<'a> {
 
     }
 
+    /// Gets the username mentions (e.g. "/u/username") of the logged-in user, newest first.
+    /// Each mention is the comment that mentioned the user, so `Content.name()` gives the full
+    /// name of the comment (`t1_...`) to reply to.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::traits::Content;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let mentions = client.messages().mentions(ListingOptions::default())
+    ///     .expect("Could not fetch mentions");
+    /// for mention in mentions.take(10) {
+    ///     println!("Mentioned in {}", mention.name());
+    /// }
+    /// ```
+    pub fn mentions(&self, opts: ListingOptions) -> Result<MessageListing<'a>, APIError> {
+        self.message_listing("mentions", opts)
+    }
+
+    /// Gets the replies to comments of the logged-in user, without private messages or other
+    /// notifications.
+    pub fn comment_replies(&self, opts: ListingOptions) -> Result<MessageListing<'a>, APIError> {
+        self.message_listing("comments", opts)
+    }
+
+    /// Gets the replies to posts of the logged-in user, without private messages or other
+    /// notifications.
+    pub fn post_replies(&self, opts: ListingOptions) -> Result<MessageListing<'a>, APIError> {
+        self.message_listing("selfreply", opts)
+    }
+
+    fn message_listing(&self, folder: &str, opts: ListingOptions)
+                       -> Result<MessageListing<'a>, APIError> {
+        let uri = format!("/message/{}?raw_json=1&limit={}", folder, opts.batch);
+        let full_uri = format!("{}&{}", uri, opts.anchor);
+        let result = self.client.get_json(&full_uri, false)?;
+        let result: MessageListingData = serde_json::from_str(&result)?;
+        Ok(MessageListing::new(self.client, uri, result.data))
+    }
+
     /// Gets a `MessageStream` of unread posts, marking each one as read after yielding it from
     /// the iterator. This can be useful to monitor /u/username mentions, replies to comments/posts
     /// and private messages.
@@ -223,11 +290,26 @@ impl<'a> MessageInterface<'a> {
 // TODO: refactor Listing to cover this case too.
 
 /// A listing of messages that will auto-paginate until all messages in the listing have been
-/// exhausted.
+/// exhausted. Like `Listing`, the next page is only requested once all messages of the current
+/// page have been yielded. If fetching a page fails, the iterator ends early and the error can be
+/// retrieved with `last_error()`.
+/// # Examples
+/// ```rust,no_run
+/// use new_rawr::auth::PasswordAuthenticator;
+/// use new_rawr::client::RedditClient;
+/// use new_rawr::options::ListingOptions;
+/// use new_rawr::traits::Editable;
+/// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+/// let inbox = client.messages().inbox(ListingOptions::default()).expect("Could not get inbox");
+/// for message in inbox.take(100) {
+///     println!("{}", message.body().unwrap());
+/// }
+/// ```
 pub struct MessageListing<'a> {
     client: &'a RedditClient,
     query_stem: String,
     data: listing::ListingData<MessageData>,
+    last_error: Option<APIError>,
 }
 
 impl<'a> MessageListing<'a> {
@@ -241,8 +323,15 @@ impl<'a> MessageListing<'a> {
             client: client,
             query_stem: query_stem,
             data: data,
+            last_error: None,
         }
     }
+
+    /// Returns the error that ended the iteration early, if fetching the next page failed.
+    /// This is `None` while the listing is still being iterated or if it was fully exhausted.
+    pub fn last_error(&self) -> Option<&APIError> {
+        self.last_error.as_ref()
+    }
 }
 
 impl<'a> PageListing for MessageListing<'a> {
@@ -264,9 +353,8 @@ impl<'a> MessageListing<'a> {
         match self.after() {
             Some(after_id) => {
                 let url = format!("{}&after={}", self.query_stem, after_id);
-                let string = self.client
-                    .get_json(&url, false).unwrap();
-                let string:MessageListingData = serde_json::from_str(&*string).unwrap();
+                let string = self.client.get_json(&url, false)?;
+                let string: MessageListingData = serde_json::from_str(&string)?;
                 Ok(MessageListing::new(self.client, self.query_stem.to_owned(), string.data))
             }
             None => Err(APIError::ExhaustedListing),
@@ -281,10 +369,18 @@ impl<'a> Iterator for MessageListing<'a> {
             if self.after().is_none() {
                 None
             } else {
-                let mut new_listing = self.fetch_after().expect("After does not exist!");
-                self.data.children.append(&mut new_listing.data.children);
-                self.data.after = new_listing.data.after;
-                self.next()
+                match self.fetch_after() {
+                    Ok(mut new_listing) => {
+                        self.data.children.append(&mut new_listing.data.children);
+                        self.data.after = new_listing.data.after;
+                        self.next()
+                    }
+                    Err(err) => {
+                        self.data.after = None;
+                        self.last_error = Some(err);
+                        None
+                    }
+                }
             }
         } else {
             let child = self.data.children.drain(..1).next().unwrap();
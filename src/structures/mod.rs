@@ -12,3 +12,9 @@ pub mod subreddit;
 pub mod user;
 /// Structures for private messages.
 pub mod messages;
+/// Structures representing subreddit wiki pages.
+pub mod wiki;
+/// Typed full names (`{kind}_{id}`) of Reddit items.
+pub mod fullname;
+/// Collections of posts in a subreddit.
+pub mod collection;
@@ -1,7 +1,7 @@
 use serde_json;
 
 
-use crate::responses::{listing, FlairSelectorResponse, FlairChoice};
+use crate::responses::{listing, FlairSelectorResponse, FlairChoice, RichtextFlair};
 use crate::client::RedditClient;
 use crate::traits::{Votable, Editable, Created, Content, Approvable, Commentable, Stickable, Lockable, Reportable, Distinguishable, Flairable, Visible};
 use crate::errors::APIError;
@@ -12,6 +12,12 @@ use crate::structures::comment_list::{CommentList, CommentStream};
 use crate::structures::listing::Listing;
 use crate::structures::comment::Comment;
 use crate::responses::listing::CommentResponse;
+use crate::options::ReplyOptions;
+use crate::structures::fullname::Fullname;
+use std::str::FromStr;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::thread;
 
 /// Structure representing a link post or self post (a submission) on Reddit.
 pub struct Submission<'a> {
@@ -26,6 +32,25 @@ impl<'a> PartialEq for Submission<'a> {
     }
 }
 
+impl<'a> Eq for Submission<'a> {}
+
+impl<'a> Hash for Submission<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+impl<'a> fmt::Debug for Submission<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Submission")
+            .field("name", &self.data.name)
+            .field("subreddit", &self.data.subreddit)
+            .field("author", &self.data.author)
+            .field("title", &self.data.title)
+            .finish()
+    }
+}
+
 
 impl<'a> Votable for Submission<'a> {
     fn score(&self) -> i64 {
@@ -68,11 +93,10 @@ impl<'a> Editable for Submission<'a> {
         self.data.edited.as_i64()
     }
 
+    /// Edits the self text of this post. Link posts have no body, so this returns
+    /// `APIError::NotEditable` without sending a request if this is a link post.
     fn edit(&mut self, text: &str) -> Result<(), APIError> {
-        let body = format!("api_type=json&text={}&thing_id={}",
-                           self.client.url_escape(text.to_owned()),
-                           self.data.name);
-        let res = self.client.post_success("/api/editusertext", &body, false);
+        let res = self.send_edit(text);
         if let Ok(()) = res {
             // TODO: should we update selftext_html?
             self.data.selftext = text.to_owned();
@@ -99,6 +123,13 @@ impl<'a> Content for Submission<'a> {
         User::new(self.client, &self.data.author)
     }
 
+    fn author_name(&self) -> Option<&str> {
+        match self.data.author.as_str() {
+            "[deleted]" | "[removed]" => None,
+            author => Some(author),
+        }
+    }
+
     fn author_flair_text(&self) -> Option<String> {
         self.data.author_flair_text.to_owned()
     }
@@ -112,8 +143,9 @@ impl<'a> Content for Submission<'a> {
     }
 
     fn delete(self) -> Result<(), APIError> {
-        let body = format!("id={}", self.data.name);
-        self.client.post_success("/api/del", &body, false)
+        // Reddit responds with 200 even if the delete is refused, so check the errors too.
+        let body = format!("api_type=json&id={}", self.data.name);
+        self.client.post_api("/api/del", &body, false).map(|_| ())
     }
     fn name(&self) -> &str {
         &self.data.name
@@ -148,6 +180,9 @@ impl<'a> Commentable<'a> for Submission<'a> {
     }
 
     fn reply(&self, text: &str) -> Result<Comment, APIError> {
+        if self.data.archived {
+            return Err(APIError::Archived);
+        }
         let body = format!("api_type=json&text={}&thing_id={}",
                            self.client.url_escape(text.to_owned()),
                            self.name());
@@ -167,7 +202,7 @@ impl<'a> Commentable<'a> for Submission<'a> {
         Ok(CommentList::new(self.client,
                             self.data.name.to_owned(),
                             self.data.name.to_owned(),
-                            result.1.data.children))
+                            result.into_comments().data.children))
     }
 }
 
@@ -180,6 +215,30 @@ impl<'a> Submission<'a> {
         }
     }
 
+    /// Gets the replies to this submission like `Commentable.replies()`, but only fetches the part
+    /// of the comment tree selected by the `ReplyOptions` (e.g. only the top 10 comments).
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// use new_rawr::options::{CommentSort, ListingOptions, ReplyOptions};
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let all = client.subreddit("all");
+    /// let post = all.hot(ListingOptions::default()).unwrap().next().unwrap();
+    /// let opts = ReplyOptions::default().sort(CommentSort::Top).limit(10).depth(1);
+    /// let top_comments = post.replies_with_options(opts).expect("Could not get replies");
+    /// ```
+    pub fn replies_with_options(self, opts: ReplyOptions) -> Result<CommentList<'a>, APIError> {
+        let url = format!("/comments/{}?raw_json=1{}", self.data.id, opts);
+        let result = self.client.get_json(&url, false)?;
+        let result: listing::CommentResponse = serde_json::from_str(&result)?;
+
+        Ok(CommentList::new(self.client,
+                            self.data.name.to_owned(),
+                            self.data.name.to_owned(),
+                            result.into_comments().data.children))
+    }
+
     /// Returns a `CommentStream` that fetches the latest comments in an infinite loop and returns
     /// it from the iterator. Comments will be ordered from oldest to newest, with up to 5 comments
     /// that exist being yielded at a time. This will poll the API every 5 seconds for updates.
@@ -203,6 +262,132 @@ impl<'a> Submission<'a> {
         &self.data.title
     }
 
+    /// The total number of awards that this post has received.
+    pub fn award_count(&self) -> u32 {
+        self.data.total_awards_received
+    }
+
+    /// The number of times that this post has been crossposted to other subreddits.
+    pub fn num_crossposts(&self) -> u64 {
+        self.data.num_crossposts
+    }
+
+    /// The full name of the original post (e.g. `t3_4uule8`) if this post is a crosspost, or
+    /// `None` if it is not.
+    pub fn crosspost_parent(&self) -> Option<String> {
+        self.data.crosspost_parent.to_owned()
+    }
+
+    /// This is `true` if contest mode is enabled for this post, which shows the comments in a
+    /// random order and hides their scores.
+    pub fn contest_mode(&self) -> bool {
+        self.data.contest_mode
+    }
+
+    /// This is `true` if the author receives inbox notifications for replies to this post.
+    pub fn send_replies(&self) -> bool {
+        self.data.send_replies
+    }
+
+    /// This is `true` if the author has pinned this post to their profile. For posts pinned to
+    /// the top of a subreddit, see `Stickable.stickied()`.
+    pub fn pinned(&self) -> bool {
+        self.data.pinned
+    }
+
+    /// This is `true` if this post is marked as original content (OC).
+    pub fn is_original_content(&self) -> bool {
+        self.data.is_original_content
+    }
+
+    /// This is `true` if this post is marked as a meta post about the subreddit.
+    pub fn is_meta(&self) -> bool {
+        self.data.is_meta
+    }
+
+    /// The comment sort that the moderators suggest for this post (e.g. `qa` or `new`), if any.
+    pub fn suggested_sort(&self) -> Option<&str> {
+        self.data.suggested_sort.as_deref()
+    }
+
+    /// The full name of the author (`t2_...`), or `None` if the account has been deleted.
+    pub fn author_fullname(&self) -> Option<&str> {
+        self.data.author_fullname.as_deref()
+    }
+
+    /// This is `true` if the author has Reddit Premium.
+    pub fn author_premium(&self) -> bool {
+        self.data.author_premium
+    }
+
+    /// This is `true` if the logged-in user has blocked the author of this post.
+    pub fn author_is_blocked(&self) -> bool {
+        self.data.author_is_blocked
+    }
+
+    /// The categories that the author chose for this post, if any.
+    pub fn post_categories(&self) -> Option<&[String]> {
+        self.data.post_categories.as_deref()
+    }
+
+    /// Tags that Reddit uses to change how this post is shown.
+    pub fn treatment_tags(&self) -> &[String] {
+        &self.data.treatment_tags
+    }
+
+    /// The name of the moderator who removed this post. This is only available to moderators.
+    pub fn removed_by(&self) -> Option<&str> {
+        self.data.removed_by.as_deref()
+    }
+
+    /// Why this post was removed (e.g. `moderator`, `author`, `deleted` or
+    /// `automod_filtered`), or `None` if it has not been removed.
+    pub fn removed_by_category(&self) -> Option<&str> {
+        self.data.removed_by_category.as_deref()
+    }
+
+    /// The type of the subreddit where this was posted, e.g. `public`, `restricted`,
+    /// `private` or `user` for posts on a user profile.
+    pub fn subreddit_type(&self) -> &str {
+        &self.data.subreddit_type
+    }
+
+    /// The full name of the subreddit where this was posted, e.g. `t5_2qh1i`.
+    pub fn subreddit_id(&self) -> &str {
+        &self.data.subreddit_id
+    }
+
+    /// Fetches the other posts of the same link, e.g. in other subreddits, to find reposts or
+    /// related discussions. Up to 100 posts are returned. Self posts have no duplicates.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// use new_rawr::traits::Content;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let post = client.get_by_id("t3_4uule8").expect("Invalid ID").get().expect("Not found");
+    /// for duplicate in post.duplicates().expect("Could not fetch duplicates") {
+    ///     println!("Also posted to /r/{}", duplicate.subreddit().name);
+    /// }
+    /// ```
+    pub fn duplicates(&self) -> Result<Vec<Submission<'a>>, APIError> {
+        let url = format!("/duplicates/{}?limit=100&raw_json=1", self.data.id);
+        let result = self.client.get_json(&url, false)?;
+        let result: listing::DuplicatesResponse = serde_json::from_str(&result)?;
+        Ok(result.into_comments()
+            .data
+            .children
+            .into_iter()
+            .map(|post| Submission::new(self.client, post.data))
+            .collect())
+    }
+
+    /// This is `true` if Reddit has archived this post (usually done 6 months after the post was
+    /// submitted), so it can no longer be replied to or voted on.
+    pub fn archived(&self) -> bool {
+        self.data.archived
+    }
+
     /// This is `true` if the post is a self post, and `false` if it is a link post.
     pub fn is_self_post(&self) -> bool {
         self.data.is_self
@@ -243,12 +428,203 @@ impl<'a> Submission<'a> {
         res
     }
 
+    /// Returns `true` if this is an AMA (Ask Me Anything) post, i.e. a self post with an event
+    /// start time.
+    pub fn is_ama(&self) -> bool {
+        self.data.post_hint.as_deref() == Some("self") &&
+        self.data.event_start.is_some()
+    }
+
+    /// Gets the event metadata of this AMA post, or `None` if this is not an AMA post (see
+    /// `Submission.is_ama()`).
+    pub fn ama_data(&self) -> Option<AmaData> {
+        if !self.is_ama() {
+            return None;
+        }
+        let event_type = if self.data.event_is_live.unwrap_or(false) {
+            "live"
+        } else {
+            "scheduled"
+        };
+        Some(AmaData {
+            start_time: self.data.event_start.unwrap() as i64,
+            end_time: self.data.event_end.map(|end| end as i64),
+            event_type: event_type.to_owned(),
+        })
+    }
+
+    /// Gets the background color of the flair as a hex code (e.g. `#ff4500`), or `None` if the
+    /// flair uses the default color.
+    pub fn flair_background_color(&self) -> Option<&str> {
+        self.data.link_flair_background_color.as_deref().filter(|color| !color.is_empty())
+    }
+
+    /// Gets the color of the flair text, which is either `dark` or `light`.
+    pub fn flair_text_color(&self) -> Option<&str> {
+        self.data.link_flair_text_color.as_deref()
+    }
+
+    /// Gets the parts (text and emojis) of the flair, if this is a richtext flair. The slice is
+    /// empty for plain text flairs, whose text is available from `Flairable.get_flair_text()`.
+    pub fn flair_richtext(&self) -> &[RichtextFlair] {
+        &self.data.link_flair_richtext
+    }
+
+    /// Marks this post as visited, so it is shown as visited on Reddit for the logged-in user.
+    /// This requires an OAuth authenticator with the `save` scope, and the account must have
+    /// Reddit Premium (gold); otherwise Reddit refuses the request with
+    /// `APIError::HTTPError(403)`.
+    pub fn mark_visited(&self) -> Result<(), APIError> {
+        let body = format!("links={}", self.data.name);
+        self.client.post_success("/api/store_visits", &body, true)
+    }
+
+    /// Reports this post to the Reddit admins rather than to the moderators of the subreddit,
+    /// for violations of Reddit's site-wide rules such as harassment or ban evasion. The
+    /// `site_reason` names the broken rule and `reason` can give further details.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let post = client.get_by_id("t3_4uule8").expect("Invalid ID").get().expect("Not found");
+    /// post.report_to_admins("Alt account of a banned user", "Ban evasion")
+    ///     .expect("Report failed");
+    /// ```
+    pub fn report_to_admins(&self, reason: &str, site_reason: &str) -> Result<(), APIError> {
+        let body = format!("api_type=json&thing_id={}&reason={}&site_reason={}\
+                            &from_help_desk=false",
+                           self.data.name,
+                           self.client.url_escape(reason.to_owned()),
+                           self.client.url_escape(site_reason.to_owned()));
+        self.client.post_success("/api/report", &body, false)
+    }
+
+    /// Fetches this post again, e.g. to show its current score, comment count and flags on a
+    /// dashboard without fetching the listing that it came from. This `Submission` is left
+    /// unchanged; the up-to-date post is returned instead.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// use new_rawr::traits::Votable;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let post = client.get_by_id("t3_4uule8").expect("Invalid ID").get().expect("Not found");
+    /// let fresh = post.fetch_fresh().expect("Could not fetch post");
+    /// println!("Score changed by {}", fresh.score() - post.score());
+    /// ```
+    pub fn fetch_fresh(&self) -> Result<Submission<'a>, APIError> {
+        LazySubmission::new(self.client, &self.data.name).get()
+    }
+
+    /// Sets the flair of this post like `Flairable.flair()`, and updates the flair text and CSS
+    /// class of this `Submission` from the `FlairChoice`, so it does not need to be fetched
+    /// again. If `refresh` is true, the post is fetched again after setting the flair, which
+    /// costs an extra request but picks up any changes Reddit made to the flair (e.g. when the
+    /// template text is filled in by a moderator).
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::traits::Flairable;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("learnprogramming");
+    /// let mut post = sub.hot(ListingOptions::default()).unwrap().next().unwrap();
+    /// let options = post.flair_options().unwrap();
+    /// let choice = options.flairs.iter().find(|f| f.flair_text == "tutorial").unwrap();
+    /// post.set_flair(choice, false).expect("Could not set flair");
+    /// assert_eq!(post.get_flair_text(), Some(String::from("tutorial")));
+    /// ```
+    pub fn set_flair(&mut self, choice: &FlairChoice, refresh: bool) -> Result<(), APIError> {
+        self.flair(&choice.flair_template_id)?;
+        if refresh {
+            let fresh = self.fetch_fresh()?;
+            self.data.link_flair_text = fresh.data.link_flair_text;
+            self.data.link_flair_css_class = fresh.data.link_flair_css_class;
+        } else {
+            self.data.link_flair_text = Some(choice.flair_text.to_owned());
+            // Reddit reports a flair without a CSS class as null rather than an empty string.
+            self.data.link_flair_css_class = Some(choice.flair_css_class.to_owned())
+                .filter(|class| !class.is_empty());
+        }
+        Ok(())
+    }
+
+    /// Edits the self text of this post and sets its flair at the same time, which takes about
+    /// as long as one of the requests instead of both after each other. The results of the
+    /// edit and the flair change are returned separately, as one can succeed while the other
+    /// fails (including when Reddit reports an error in `json.errors`); the text and flair of
+    /// this `Submission` are only updated for the changes that succeeded. Like
+    /// `Editable.edit()`, link posts give `APIError::NotEditable` for the edit.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::traits::Flairable;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let mut post = client.get_by_id("t3_4uule8").expect("Invalid ID").get().expect("Not found");
+    /// let options = post.flair_options().expect("Could not get flairs");
+    /// let solved = options.flairs.iter().find(|f| f.flair_text == "solved").unwrap();
+    /// let (edit, flair) = post.edit_and_flair("**Edit:** this is solved now.", solved);
+    /// edit.expect("Edit failed");
+    /// flair.expect("Flair failed");
+    /// ```
+    pub fn edit_and_flair(&mut self, text: &str, choice: &FlairChoice)
+                          -> (Result<(), APIError>, Result<(), APIError>) {
+        let this = &*self;
+        let (edit, flair) = thread::scope(|scope| {
+            let edit = scope.spawn(move || this.send_edit(text));
+            let flair = this.send_flair(&choice.flair_template_id);
+            (edit.join().unwrap_or(Err(APIError::RequestPanicked)), flair)
+        });
+        if edit.is_ok() {
+            self.data.selftext = text.to_owned();
+        }
+        if flair.is_ok() {
+            self.data.link_flair_text = Some(choice.flair_text.to_owned());
+            self.data.link_flair_css_class = Some(choice.flair_css_class.to_owned())
+                .filter(|class| !class.is_empty());
+        }
+        (edit, flair)
+    }
+
+    /// Sends the edit request for `Editable.edit()` without updating this `Submission`.
+    fn send_edit(&self, text: &str) -> Result<(), APIError> {
+        if !self.is_self_post() {
+            return Err(APIError::NotEditable);
+        }
+        let body = format!("api_type=json&text={}&thing_id={}",
+                           self.client.url_escape(text.to_owned()),
+                           self.data.name);
+        self.client.post_api("/api/editusertext", &body, false).map(|_| ())
+    }
+
+    /// Sends the flair request for `Flairable.flair()`.
+    fn send_flair(&self, template: &str) -> Result<(), APIError> {
+        let body = format!("api_type=json&link={}&flair_template_id={}",
+                           self.data.name,
+                           template);
+        let url = format!("/r/{}/api/selectflair", self.data.subreddit);
+        self.client.post_api(&url, &body, false).map(|_| ())
+    }
+
     fn vote(&self, dir: i8) -> Result<(), APIError> {
         let body = format!("dir={}&id={}", dir, self.data.name);
         self.client.post_success("/api/vote", &body, false)
     }
 }
 
+/// Event metadata of an AMA (Ask Me Anything) post. See `Submission.ama_data()`.
+pub struct AmaData {
+    /// The UTC timestamp of the start of the AMA.
+    pub start_time: i64,
+    /// The UTC timestamp of the end of the AMA, if set.
+    pub end_time: Option<i64>,
+    /// `live` if the AMA is currently running, otherwise `scheduled`.
+    pub event_type: String,
+}
+
 impl<'a> Stickable for Submission<'a> {
     /// This is `true` if the post is stickied (an announcement post).
     fn stickied(&self) -> bool {
@@ -317,6 +693,14 @@ impl<'a> Reportable for Submission<'a> {
     fn report_count(&self) -> Option<u64> {
         self.data.num_reports.to_owned()
     }
+
+    fn mod_reports(&self) -> Vec<(String, String)> {
+        self.data.mod_reports.to_owned()
+    }
+
+    fn user_reports(&self) -> Vec<(String, u64)> {
+        self.data.user_reports.to_owned()
+    }
 }
 
 impl<'a> Distinguishable for Submission<'a> {
@@ -362,11 +746,7 @@ impl<'a> Flairable for Submission<'a> {
     }
 
     fn flair(&self, template: &str) -> Result<(), APIError> {
-        let body = format!("api_type=json&link={}&flair_template_id={}",
-                           self.data.name,
-                           template);
-        let url = format!("/r/{}/api/selectflair", self.data.subreddit);
-        self.client.post_success(&url, &body, false)
+        self.send_flair(template)
     }
 }
 
@@ -400,6 +780,7 @@ impl<'a> Visible for Submission<'a> {
 
 /// A list of flairs that can be assigned to a post. To access the complete list, use
 /// `FlairList.flairs`, which is a list of `FlairChoice` objects.
+#[derive(Debug, Clone)]
 pub struct FlairList {
     /// The list of flairs available.
     pub flairs: Vec<FlairChoice>,
@@ -434,6 +815,34 @@ impl FlairList {
 
         None
     }
+
+    /// Finds the template ID of the flair with the specified text like `FlairList.find_text()`,
+    /// but without consuming the `FlairList`, so it can be searched again.
+    pub fn find_text_ref(&self, text: &str) -> Option<&str> {
+        self.iter()
+            .find(|flair| flair.flair_text == text)
+            .map(|flair| flair.flair_template_id.as_str())
+    }
+
+    /// Iterates over the available flairs without consuming the `FlairList`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::traits::Flairable;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("learnprogramming");
+    /// let post = sub.hot(ListingOptions::default()).unwrap().next().unwrap();
+    /// let options = post.flair_options().unwrap();
+    /// for flair in options.iter() {
+    ///     println!("{}: {}", flair.flair_template_id, flair.flair_text);
+    /// }
+    /// let tutorial = options.find_text_ref("tutorial");
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &FlairChoice> {
+        self.flairs.iter()
+    }
 }
 
 /// A lazy object representing a submission. Used by the `Client.get_by_id()` method until the
@@ -457,22 +866,27 @@ impl<'a> LazySubmission<'a> {
     /// creation time.
     pub fn get(self) -> Result<Submission<'a>, APIError> {
         let url = format!("/by_id/{}?raw_json=1", self.id);
-        let string = self.client
-            .get_json(&url, false).unwrap();
-        let string: listing::Listing = serde_json::from_str(&*string).unwrap();
+        let string = self.client.get_json(&url, false)?;
+        let string: listing::Listing = serde_json::from_str(&string)?;
         let mut string = Listing::new(self.client, url, string.data);
-        Ok(string.next().unwrap())
+        string.next().ok_or(APIError::ExhaustedListing)
     }
 
     /// Fetches a `CommentList` with replies to this submission.
     pub fn replies(self) -> Result<CommentList<'a>, APIError> {
-        let url = format!("/comments/{}?raw_json=1", self.id.split('_').nth(1).unwrap());
-        let string = self.client
-            .get_json(&url, false).unwrap();
-        let string :listing::CommentResponse =serde_json::from_str(&*string).unwrap();
+        self.replies_with_options(ReplyOptions::default())
+    }
+
+    /// Fetches a `CommentList` with the part of the replies selected by the `ReplyOptions`. See
+    /// `Submission.replies_with_options()` for an example.
+    pub fn replies_with_options(self, opts: ReplyOptions) -> Result<CommentList<'a>, APIError> {
+        let id = Fullname::from_str(&self.id)?;
+        let url = format!("/comments/{}?raw_json=1{}", id.id36(), opts);
+        let result = self.client.get_json(&url, false)?;
+        let result: listing::CommentResponse = serde_json::from_str(&result)?;
         Ok(CommentList::new(self.client,
                             self.id.to_owned(),
                             self.id.to_owned(),
-                            string.1.data.children))
+                            result.into_comments().data.children))
     }
 }
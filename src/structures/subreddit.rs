@@ -1,17 +1,34 @@
 #![allow(unknown_lints, wrong_self_convention, new_ret_no_self)]
 
 use crate::client::RedditClient;
-use crate::options::{ListingOptions, TimeFilter, LinkPost, SelfPost};
+use crate::options::{ListingOptions, TimeFilter, LinkPost, SelfPost, SearchSort, SearchOptions, SearchResultType,
+                     LuceneQuery, GalleryPost, CommentSort, ReplyOptions, CollectionLayout, FlairTemplateSpec, BanOptions};
 use crate::structures::listing::Listing;
 use crate::responses::listing;
-use crate::traits::Created;
-use crate::errors::APIError;
+use crate::responses::listing::SubredditSettings;
+use crate::responses::subreddit::{CollectionData, Emoji, EmojisResponse, FlairTemplate, PostRequirements, Rule,
+                                  RulesResponse};
+use crate::traits::{Content, Created};
+use crate::errors::{APIError, RedditError, SubmitError};
 use crate::structures::listing::PostStream;
-use hyper::Body;
-use crate::structures::user::UserListing;
+use hyper::{Body, StatusCode};
+use hyper::header::{CONTENT_TYPE, LOCATION};
+use crate::structures::user::{BannedUserListing, UserListing};
+use crate::structures::comment::Comment;
+use crate::structures::submission::Submission;
+use crate::structures::comment_list::CommentList;
+use crate::structures::fullname::Fullname;
+use crate::structures::collection::Collection;
+use crate::responses::BasicThing;
+use crate::responses::listing::ListingData;
+use crate::structures::wiki::{self, WikiPage};
 use std::error::Error;
 use serde_json::Value;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
 use std::str::FromStr;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// The `Subreddit` struct represents a subreddit and allows access to post listings
 /// and data about the subreddit.
@@ -21,9 +38,24 @@ pub struct Subreddit<'a> {
     client: &'a RedditClient,
 }
 
+// Reddit treats subreddit names case-insensitively, so they are compared and hashed that way too.
 impl<'a> PartialEq for Subreddit<'a> {
     fn eq(&self, other: &Subreddit) -> bool {
-        self.name == other.name
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl<'a> Eq for Subreddit<'a> {}
+
+impl<'a> Hash for Subreddit<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.to_ascii_lowercase().hash(state);
+    }
+}
+
+impl<'a> fmt::Debug for Subreddit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Subreddit").field("name", &self.name).finish()
     }
 }
 
@@ -31,12 +63,19 @@ impl<'a> Subreddit<'a> {
     fn get_feed(&self, ty: &str, opts: ListingOptions) -> Result<Listing, APIError> {
         // We do not include the after/before parameter here so the pagination can adjust it later
         // on.
-        let uri = format!("/r/{}/{}limit={}&raw_json=1", self.name, ty, opts.batch);
+        let mut uri = format!("/r/{}/{}limit={}&raw_json=1", self.name, ty, opts.batch);
+        if opts.include_nsfw {
+            uri.push_str("&include_over_18=on");
+        }
         let full_uri = format!("{}&{}", uri, opts.anchor);
-        let string = self.client
-            .get_json(&full_uri, false).unwrap();
-        let string: listing::Listing = serde_json::from_str(&*string).unwrap();
-        Ok(Listing::new(self.client, uri, string.data))
+        let string = self.client.get_json(&full_uri, false)?;
+        let string: listing::Listing = serde_json::from_str(&string)?;
+        let listing = Listing::new(self.client, uri, string.data);
+        if opts.exclude_sticky {
+            Ok(listing.without_stickies())
+        } else {
+            Ok(listing)
+        }
     }
 
     /// Creates a `Subreddit` from a client and the subreddit's name. Do not use this directly -
@@ -49,7 +88,8 @@ impl<'a> Subreddit<'a> {
     }
 
     /// Gets a listing of the hot feed for this subreddit. The first page may include some sticky
-    /// posts in addtion to the expected posts.
+    /// posts in addtion to the expected posts. To skip these, use
+    /// `ListingOptions::default().no_stickies()`.
     /// # Examples
     /// ```
     /// use new_rawr::client::RedditClient;
@@ -63,6 +103,66 @@ impl<'a> Subreddit<'a> {
         self.get_feed("hot?", opts)
     }
 
+    /// Fetches one of the posts pinned to the top of this subreddit, where `num` is 1 for the
+    /// first sticky post or 2 for the second. The comments of the post are not loaded. If there
+    /// is no such sticky post, Reddit responds with `APIError::HTTPError(404)`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("learnprogramming");
+    /// let announcement = sub.sticky(1).expect("Could not fetch sticky post");
+    /// println!("Pinned: {}", announcement.title());
+    /// ```
+    pub fn sticky(&self, num: u8) -> Result<Submission<'a>, APIError> {
+        let url = format!("/r/{}/about/sticky?num={}&limit=0&raw_json=1", self.name, num);
+        self.post_or_redirect(&url)
+    }
+
+    /// Fetches a random post from this subreddit. The comments of the post are not loaded.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let post = client.subreddit("aww").random().expect("Could not fetch a post");
+    /// println!("{}", post.title());
+    /// ```
+    pub fn random(&self) -> Result<Submission<'a>, APIError> {
+        let url = format!("/r/{}/random?limit=0&raw_json=1", self.name);
+        self.post_or_redirect(&url)
+    }
+
+    /// Fetches a post from an endpoint that either responds with the post and its comments, or
+    /// redirects to the comments page of the post, which then has to be fetched separately.
+    fn post_or_redirect(&self, url: &str) -> Result<Submission<'a>, APIError> {
+        let found = self.client.ensure_authenticated(|| {
+            let request = self.client.get(url, false)?.body(Body::empty()).unwrap();
+            let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
+            let response = runtime.block_on(self.client.send(request))?;
+            let status = response.status();
+            if status.is_redirection() {
+                let location = response.headers().get(LOCATION).and_then(|l| l.to_str().ok());
+                location.map(|l| Err(l.to_owned())).ok_or(APIError::HTTPError(status))
+            } else if status.is_success() {
+                let body = runtime.block_on(hyper::body::to_bytes(response.into_body()))?;
+                let response: listing::CommentResponse = serde_json::from_slice(&body)?;
+                let post = response.into_link().data.children.into_iter().next();
+                Ok(Ok(post.ok_or(APIError::ExhaustedListing)?.data))
+            } else {
+                Err(APIError::HTTPError(status))
+            }
+        })?;
+        match found {
+            Ok(data) => Ok(Submission::new(self.client, data)),
+            Err(location) if location.starts_with('/') => {
+                self.client.get_by_url(&format!("https://www.reddit.com{}", location))
+            }
+            Err(location) => self.client.get_by_url(&location),
+        }
+    }
+
     /// Gets a `PostStream` of the new posts in the subreddit. The iterator will yield values
     /// forever, unless it is manually ended at some point. For tips, check the `PostStream` class.
     /// # Examples
@@ -97,6 +197,127 @@ impl<'a> Subreddit<'a> {
         self.get_feed("new?", opts)
     }
 
+    /// Fetches a post in this subreddit together with its comments, sorted by `sort`, in a
+    /// single request. The ID can be the base-36 ID (`4uule8`) or the full name (`t3_4uule8`).
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::CommentSort;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("learnprogramming");
+    /// let (post, comments) = sub.get_post_and_comments("4uule8", CommentSort::Top)
+    ///     .expect("Could not fetch post");
+    /// println!("{} has {} top-level comments", post.title(), comments.count());
+    /// ```
+    pub fn get_post_and_comments(&self, post_id: &str, sort: CommentSort)
+                                 -> Result<(Submission<'a>, CommentList<'a>), APIError> {
+        let id = match Fullname::from_str(post_id) {
+            Ok(name) => name.expect_kind("t3")?.id36().to_owned(),
+            Err(_) => Fullname::from_str(&format!("t3_{}", post_id))?.id36().to_owned(),
+        };
+        let url = format!("/r/{}/comments/{}?raw_json=1{}",
+                          self.name,
+                          id,
+                          ReplyOptions::default().sort(sort));
+        let string = self.client.get_json(&url, false)?;
+        let response: listing::CommentResponse = serde_json::from_str(&string)?;
+        let (post, comments) = response.into_parts();
+        let post = post.data.children.into_iter().next().ok_or(APIError::ExhaustedListing)?;
+        let post = Submission::new(self.client, post.data);
+        let name = post.name().to_owned();
+        let comments = CommentList::new(self.client, name.to_owned(), name, comments.data.children);
+        Ok((post, comments))
+    }
+
+    /// Gets every post submitted to this subreddit since the post with the specified full name
+    /// (e.g. `t3_4uule8`), newest first. Returns an empty `Vec` if there are no new posts. Only
+    /// `opts.batch` is used; the anchor is ignored.
+    ///
+    /// This is a simpler alternative to `Subreddit.new_stream()` for bots that run periodically:
+    /// store the name of the first post returned and pass it to the next call. If the stored
+    /// post has been deleted, Reddit cannot find it and the result will be empty.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::traits::Content;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("learnprogramming");
+    /// let posts = sub.new_posts_since("t3_4uule8", ListingOptions::default())
+    ///     .expect("Could not fetch posts");
+    /// for post in &posts {
+    ///     println!("{}", post.title());
+    /// }
+    /// ```
+    pub fn new_posts_since(&self, since_fullname: &str, opts: ListingOptions)
+                           -> Result<Vec<Submission<'a>>, APIError> {
+        let posts = self.fetch_before("new", since_fullname, opts.batch)?;
+        Ok(posts.into_iter().map(|data| Submission::new(self.client, data)).collect())
+    }
+
+    /// Gets every comment posted in this subreddit since the comment with the specified full name
+    /// (e.g. `t1_d5t5q3c`), newest first. Only `opts.batch` is used; the anchor is ignored.
+    ///
+    /// This is intended for polling: store the name of the first comment returned and pass it
+    /// to the next call, so each comment is only seen once. If the stored comment has been
+    /// deleted, Reddit cannot find it and the result will be empty.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::ListingOptions;
+    /// use new_rawr::traits::Content;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("learnprogramming");
+    /// let mut last_seen = String::from("t1_d5t5q3c");
+    /// let comments = sub.comments_before(&last_seen, ListingOptions::default())
+    ///     .expect("Could not fetch comments");
+    /// if let Some(newest) = comments.first() {
+    ///     last_seen = newest.name().to_owned();
+    /// }
+    /// ```
+    pub fn comments_before(&self, fullname: &str, opts: ListingOptions)
+                           -> Result<Vec<Comment<'a>>, APIError> {
+        let comments = self.fetch_before("comments", fullname, opts.batch)?;
+        Ok(comments.into_iter().map(|data| Comment::new(self.client, data)).collect())
+    }
+
+    /// Follows the `before` links of the specified feed from the given full name until no newer
+    /// items remain, and returns every item found, newest first.
+    fn fetch_before<T>(&self, feed: &str, fullname: &str, batch: u8) -> Result<VecDeque<T>, APIError>
+        where T: DeserializeOwned
+    {
+        let mut items = VecDeque::new();
+        let mut before = fullname.to_owned();
+        loop {
+            let url = self.before_page_url(feed, &before, batch, items.len());
+            let string = self.client.get_json(&url, false)?;
+            let page: BasicThing<ListingData<T>> = serde_json::from_str(&string)?;
+            // Each page is newer than the last, so it goes in front of the items so far.
+            for child in page.data.children.into_iter().rev() {
+                items.push_front(child.data);
+            }
+            match page.data.before {
+                Some(next) => before = next,
+                None => return Ok(items),
+            }
+        }
+    }
+
+    /// The URL of a page of `fetch_before()`. Like `Listing`, it sends the number of items seen
+    /// so far in `count`, without which Reddit may leave out the `before` token of the next page.
+    pub(crate) fn before_page_url(&self, feed: &str, before: &str, batch: u8, count: usize)
+                                  -> String {
+        format!("/r/{}/{}?count={}&before={}&limit={}&raw_json=1",
+                self.name,
+                feed,
+                count,
+                before,
+                batch)
+    }
+
     /// Gets a listing of the rising feed for this subreddit. Usually much shorter than the other
     /// listings; may be empty.
     /// # Examples
@@ -144,6 +365,82 @@ impl<'a> Subreddit<'a> {
         self.get_feed(&path, opts)
     }
 
+    /// Searches the posts in this subreddit for the specified plain text query.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{ListingOptions, SearchSort, TimeFilter};
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("rust");
+    /// let results = sub.search("async", TimeFilter::Year, SearchSort::Top,
+    ///                          ListingOptions::default()).expect("Search failed");
+    /// ```
+    pub fn search(&self,
+                  query: &str,
+                  time: TimeFilter,
+                  sort: SearchSort,
+                  opts: ListingOptions)
+                  -> Result<Listing<'_>, APIError> {
+        self.search_with_syntax(query, "plain", time, sort, opts)
+    }
+
+    /// Searches the posts in this subreddit for the specified plain text query, with the order
+    /// and time span given in the `SearchOptions`. Unlike other listings, search results can be
+    /// sorted by their number of comments.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{SearchOptions, SearchSort, TimeFilter};
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("rust");
+    /// let opts = SearchOptions::default().sort(SearchSort::Comments).time(TimeFilter::Week);
+    /// let results = sub.search_with_sort("async", opts).expect("Search failed");
+    /// ```
+    pub fn search_with_sort(&self, query: &str, opts: SearchOptions)
+                            -> Result<Listing<'_>, APIError> {
+        self.search_with_syntax(query, "plain", opts.time, opts.sort, opts.listing)
+    }
+
+    /// Searches the posts in this subreddit using Reddit's Lucene syntax, which allows searching
+    /// by title, flair, author and more. See `LuceneQuery` for the supported operators.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{ListingOptions, LuceneQuery, SearchSort, TimeFilter};
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("rust");
+    /// let query = LuceneQuery::new().title("release").flair("announcement");
+    /// let results = sub.search_posts_lucene(query, TimeFilter::AllTime, SearchSort::New,
+    ///                                       ListingOptions::default()).expect("Search failed");
+    /// ```
+    pub fn search_posts_lucene(&self,
+                               query: LuceneQuery,
+                               time: TimeFilter,
+                               sort: SearchSort,
+                               opts: ListingOptions)
+                               -> Result<Listing<'_>, APIError> {
+        self.search_with_syntax(&query.build(), "lucene", time, sort, opts)
+    }
+
+    fn search_with_syntax(&self,
+                          query: &str,
+                          syntax: &str,
+                          time: TimeFilter,
+                          sort: SearchSort,
+                          opts: ListingOptions)
+                          -> Result<Listing<'_>, APIError> {
+        let path = format!("search?q={}&restrict_sr=on&syntax={}&{}&{}{}&",
+                           self.client.url_escape(query.to_owned()),
+                           syntax,
+                           SearchResultType::Link,
+                           sort,
+                           time);
+        self.get_feed(&path, opts)
+    }
+
     /// Submits a link post to this subreddit using the specified parameters. If the link has
     /// already been posted, this will fail unless you specifically allow reposts.
     /// # Examples
@@ -169,6 +466,7 @@ impl<'a> Subreddit<'a> {
                            self.name,
                            self.client.url_escape(post.title.to_owned()),
                            self.client.url_escape(post.link.to_owned()));
+        let body = self.with_flair(body, post.flair_id, post.flair_text);
         self.client.post_success("/api/submit", &body, false)
     }
 
@@ -190,19 +488,300 @@ impl<'a> Subreddit<'a> {
                            self.name,
                            self.client.url_escape(post.title),
                            self.client.url_escape(post.text));
+        let body = self.with_flair(body, post.flair_id, post.flair_text);
         self.client.post_success("/api/submit", &body, false)
     }
-    /// Invites a new member to the subreddit.
+
+    fn with_flair(&self,
+                  mut body: String,
+                  flair_id: Option<String>,
+                  flair_text: Option<String>)
+                  -> String {
+        if let Some(flair_id) = flair_id {
+            body.push_str(&format!("&flair_id={}", self.client.url_escape(flair_id)));
+        }
+        if let Some(flair_text) = flair_text {
+            body.push_str(&format!("&flair_text={}", self.client.url_escape(flair_text)));
+        }
+        body
+    }
+
+    /// Fetches the rules that new posts in this subreddit must follow, such as required flairs or
+    /// blocked domains. This requires an OAuth authenticator.
+    pub fn post_requirements(&self) -> Result<PostRequirements, APIError> {
+        let url = format!("/api/v1/{}/post_requirements?raw_json=1", self.name);
+        let string = self.client.get_json(&url, true)?;
+        Ok(serde_json::from_str(&string)?)
+    }
+
+    /// Checks the post against `Subreddit.post_requirements()` and only submits it with
+    /// `Subreddit.submit_text()` if it meets them, so that the reason for a rejection is known
+    /// without a failed submission. Posts to subreddits that require a flair are rejected with
+    /// `SubmitError::FlairRequired` unless a flair was set with `SelfPost::flair()`.
+    pub fn submit_text_validated(&self, post: SelfPost) -> Result<(), SubmitError> {
+        let requirements = self.post_requirements()?;
+        Subreddit::validate_title(&post.title, &requirements)?;
+        if requirements.body_restriction_policy.as_deref() == Some("required") &&
+           post.text.trim().is_empty() {
+            return Err(SubmitError::BodyRequired);
+        }
+        if requirements.is_flair_required && post.flair_id.is_none() {
+            return Err(SubmitError::FlairRequired);
+        }
+        Ok(self.submit_text(post)?)
+    }
+
+    /// Checks the post against `Subreddit.post_requirements()` and only submits it with
+    /// `Subreddit.submit_link()` if it meets them. See `Subreddit.submit_text_validated()`.
+    pub fn submit_link_validated(&self, post: LinkPost) -> Result<(), SubmitError> {
+        let requirements = self.post_requirements()?;
+        Subreddit::validate_title(&post.title, &requirements)?;
+        let domain = Subreddit::link_domain(&post.link);
+        let matches = |allowed: &String| {
+            let allowed = allowed.to_lowercase();
+            domain == allowed || domain.ends_with(&format!(".{}", allowed))
+        };
+        if requirements.domain_blacklist.iter().any(&matches) ||
+           (!requirements.domain_whitelist.is_empty() &&
+            !requirements.domain_whitelist.iter().any(&matches)) {
+            return Err(SubmitError::DomainBlocked(domain));
+        }
+        if requirements.is_flair_required && post.flair_id.is_none() {
+            return Err(SubmitError::FlairRequired);
+        }
+        Ok(self.submit_link(post)?)
+    }
+
+    fn validate_title(title: &str, requirements: &PostRequirements) -> Result<(), SubmitError> {
+        // Reddit never accepts titles longer than 300 characters.
+        let max = requirements.title_text_max_length.unwrap_or(300).min(300);
+        if title.chars().count() > max {
+            return Err(SubmitError::TitleTooLong(max));
+        }
+        let lower = title.to_lowercase();
+        let required = &requirements.title_required_strings;
+        if !required.is_empty() && !required.iter().any(|s| lower.contains(&s.to_lowercase())) {
+            return Err(SubmitError::TitleMissingRequired(required.to_owned()));
+        }
+        if let Some(blacklisted) = requirements.title_blacklisted_strings
+            .iter()
+            .find(|s| lower.contains(&s.to_lowercase())) {
+            return Err(SubmitError::TitleBlacklisted(blacklisted.to_owned()));
+        }
+        Ok(())
+    }
+
+    fn link_domain(link: &str) -> String {
+        let without_scheme = link.split("://").nth(1).unwrap_or(link);
+        let host = without_scheme.split(['/', ':', '?', '#'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        host.trim_start_matches("www.").to_owned()
+    }
+
+    /// Submits a gallery (multi-image) post to this subreddit. The images are uploaded to Reddit
+    /// first (one request per image, up to 4 at once with `RedditClient.batch()`), and the post
+    /// is only submitted once every upload has succeeded. If Reddit refuses the post (e.g.
+    /// because of the title or a rate limit), the reason is returned as an
+    /// `APIError::RedditError`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{GalleryItem, GalleryPost};
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// let first = GalleryItem::new(std::fs::read("first.png").unwrap(), "image/png");
+    /// let second = GalleryItem::new(std::fs::read("second.jpg").unwrap(), "image/jpeg");
+    /// let post = GalleryPost::new("My photos", vec![first, second]);
+    /// sub.submit_gallery(post).expect("Posting failed!");
+    /// ```
+    pub fn submit_gallery(&self, post: GalleryPost) -> Result<(), APIError> {
+        let uploads = self.client.batch(&post.items, 4, |item| {
+            self.client.upload_media(&item.image_data, &item.mime_type)
+        });
+        let mut items = Vec::new();
+        for (item, media_id) in post.items.iter().zip(uploads) {
+            let media_id = media_id?;
+            items.push(serde_json::json!({
+                "media_id": media_id,
+                "caption": item.caption.to_owned().unwrap_or_default(),
+                "outbound_url": item.outbound_url.to_owned().unwrap_or_default(),
+            }));
+        }
+        let body = serde_json::json!({
+            "api_type": "json",
+            "sr": self.name,
+            "title": post.title,
+            "items": items,
+            "nsfw": post.nsfw,
+            "spoiler": post.spoiler,
+            "sendreplies": true,
+            "show_error_list": true,
+        }).to_string();
+        let url = "/api/submit_gallery_post.json";
+        self.client.ensure_authenticated(|| {
+            let request = self.client
+                .post(url, false)?
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.clone()))
+                .unwrap();
+
+            let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
+
+            let response = runtime.block_on(self.client.send(request))?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(APIError::HTTPError(status));
+            }
+            // As with `RedditClient.post_api()`, refused posts (e.g. without a required flair)
+            // are reported in `json.errors` of a HTTP 200 response.
+            let response = runtime.block_on(hyper::body::to_bytes(response.into_body()))?;
+            let response: Value = serde_json::from_slice(&response)?;
+            match RedditError::from_response(&response) {
+                Some(err) => Err(APIError::RedditError(err)),
+                None => Ok(()),
+            }
+        })
+    }
+
+    /// Invites a new member to the subreddit. If Reddit refuses the invite (e.g. the user is
+    /// already a contributor), `APIError::RedditError` is returned.
+    #[deprecated(note = "use `Subreddit.add_approved_user()` instead")]
     pub fn invite_member(&self, username: String) -> Result<bool, APIError> {
+        self.add_approved_user(&username)
+    }
+
+    /// Adds a user to the approved users (contributors) of this subreddit, allowing them to
+    /// submit to it when it is private or restricted.
+    ///
+    /// If the user does not exist, `APIError::RedditError` is returned with the code
+    /// `USER_DOESNT_EXIST`. If you are not a moderator with the 'access' permission, Reddit
+    /// responds with `APIError::HTTPError(403)`.
+    pub fn add_approved_user(&self, username: &str) -> Result<bool, APIError> {
         let path = format!("/r/{}/api/friend", self.name);
-        let body = format!("name={}&type=contributor", username);
-        let result = self.client.post_json(&*path, &body, false);
-        if result.is_err() {
-            return Err(result.err().unwrap());
+        let body = format!("api_type=json&name={}&type=contributor",
+                           self.client.url_escape(username.to_owned()));
+        let value = self.client.post_api(&path, &body, false)?;
+        Ok(value["success"].as_bool().unwrap_or(true))
+    }
+
+    /// Removes a user from the approved users (contributors) of this subreddit. Errors are
+    /// reported in the same way as `Subreddit.add_approved_user()`.
+    pub fn remove_approved_user(&self, username: &str) -> Result<(), APIError> {
+        let path = format!("/r/{}/api/unfriend", self.name);
+        let body = format!("api_type=json&name={}&type=contributor",
+                           self.client.url_escape(username.to_owned()));
+        self.client.post_api(&path, &body, false).map(|_| ())
+    }
+
+    /// Bans a user from this subreddit. Reddit notifies the user of the ban with a private
+    /// message, which includes `opts.message` if it is set. You must be a moderator with the
+    /// 'access' permission to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::BanOptions;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// let opts = BanOptions::new("Spam").duration(3).with_message("Please read the rules.");
+    /// sub.ban_user("Aurora0001", opts).expect("Could not ban user");
+    /// ```
+    pub fn ban_user(&self, username: &str, opts: BanOptions) -> Result<(), APIError> {
+        let mut body = format!("api_type=json&type=banned&name={}&ban_reason={}",
+                               self.client.url_escape(username.to_owned()),
+                               self.client.url_escape(opts.reason));
+        // Leaving out the duration makes the ban permanent.
+        if let Some(days) = opts.duration {
+            body.push_str(&format!("&duration={}", days));
         }
-        let value: Value = serde_json::from_str(&*result.unwrap()).unwrap();
-        let x = value["success"].as_bool().unwrap();
-        Ok(x)
+        if let Some(note) = opts.note {
+            body.push_str(&format!("&note={}", self.client.url_escape(note)));
+        }
+        if let Some(message) = opts.message {
+            body.push_str(&format!("&ban_message={}", self.client.url_escape(message)));
+        }
+        let url = format!("/r/{}/api/friend", self.name);
+        self.client.post_api(&url, &body, false).map(|_| ())
+    }
+
+    /// Bans a user from this subreddit and sends them a message with the ban notification, in
+    /// a single request. A `duration` of `None` bans the user permanently. See
+    /// `Subreddit.ban_user()` for more options.
+    pub fn ban_and_message(&self,
+                           username: &str,
+                           reason: &str,
+                           duration: Option<u32>,
+                           message_to_user: &str)
+                           -> Result<(), APIError> {
+        let mut opts = BanOptions::new(reason).with_message(message_to_user);
+        opts.duration = duration;
+        self.ban_user(username, opts)
+    }
+
+    /// Fetches the custom emojis of this subreddit, along with Reddit's own emojis which can be
+    /// used everywhere, sorted by name. This requires an OAuth authenticator.
+    pub fn emojis(&self) -> Result<Vec<Emoji>, APIError> {
+        let url = format!("/api/v1/{}/emojis/all", self.name);
+        let string = self.client.get_json(&url, true)?;
+        let groups: EmojisResponse = serde_json::from_str(&string)?;
+        let mut emojis = groups.into_values()
+            .flat_map(|group| group.into_iter())
+            .map(|(name, mut emoji)| {
+                emoji.name = name;
+                emoji
+            })
+            .collect::<Vec<_>>();
+        emojis.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(emojis)
+    }
+
+    /// Fetches the rules of this subreddit, in order.
+    pub fn rules(&self) -> Result<Vec<Rule>, APIError> {
+        let url = format!("/r/{}/about/rules?raw_json=1", self.name);
+        let string = self.client.get_json(&url, false)?;
+        let string: RulesResponse = serde_json::from_str(&string)?;
+        Ok(string.rules)
+    }
+
+    /// Fetches the rules of this subreddit and formats them as Markdown, with a heading and the
+    /// description of each rule, e.g. for posting in a comment or wiki page.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let rules = client.subreddit("learnprogramming").rules_markdown().unwrap();
+    /// // ## Rule 1: No homework
+    /// // Do not ask us to do your homework for you.
+    /// println!("{}", rules);
+    /// ```
+    pub fn rules_markdown(&self) -> Result<String, APIError> {
+        let rules = self.rules()?;
+        let sections = rules.iter()
+            .enumerate()
+            .map(|(i, rule)| {
+                let heading = format!("## Rule {}: {}", i + 1, rule.short_name);
+                if rule.description.is_empty() {
+                    heading
+                } else {
+                    format!("{}\n{}", heading, rule.description.trim())
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(sections.join("\n\n"))
+    }
+
+    /// Fetches the rules of this subreddit and formats their titles as a numbered Markdown list.
+    pub fn rules_summary(&self) -> Result<String, APIError> {
+        let rules = self.rules()?;
+        let lines = rules.iter()
+            .enumerate()
+            .map(|(i, rule)| format!("{}. {}", i + 1, rule.short_name))
+            .collect::<Vec<_>>();
+        Ok(lines.join("\n"))
     }
 
     /// Fetches information about a subreddit such as subscribers, active users and sidebar
@@ -219,24 +798,426 @@ impl<'a> Subreddit<'a> {
     pub fn about(&self) -> Result<SubredditAbout, APIError> {
         let url = format!("/r/{}/about?raw_json=1", self.name);
 
-        let string = self.client
-            .get_json(&url, false).unwrap();
-        let string: listing::SubredditAboutData = serde_json::from_str(&*string).unwrap();
-        Ok(SubredditAbout::new(string))
-    }
-    ///  Get users
-    pub fn contributors(&self) -> Result<UserListing, APIError> {
-        let url = format!("/r/{}/about/contributors?raw_json=1", self.name);
-        let string = self.client
-            .get_json(&url, false).unwrap();
-        let json: Result<listing::UserListing, serde_json::Error> = serde_json::from_str(string.as_str());
-        if json.is_err() {
-            println!("{}", &json.err().unwrap().to_string());
-            return Err(APIError::ExhaustedListing);
-        } else {
-            return Ok(UserListing::new(self.client, url, json.unwrap()));
+        let string = self.client.get_json(&url, false)?;
+        let string: listing::SubredditAbout = serde_json::from_str(&string)?;
+        Ok(SubredditAbout::new(string.data))
+    }
+    /// Gets the approved contributors of this subreddit. The listing will continue yielding
+    /// users until every contributor has been exhausted. You must be a moderator to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// for user in client.subreddit("new_rawr").contributors().expect("Request failed") {
+    ///     println!("{}", user.name);
+    /// }
+    /// ```
+    pub fn contributors(&self) -> Result<UserListing<'_>, APIError> {
+        self.user_list("contributors")
+    }
+
+    /// Counts the approved contributors of this subreddit. Reddit does not report the total, so
+    /// the contributor listing is fetched 100 users at a time and the page sizes are added up.
+    /// You must be a moderator to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let count = client.subreddit("new_rawr").contributor_count().expect("Request failed");
+    /// println!("{} approved users", count);
+    /// ```
+    pub fn contributor_count(&self) -> Result<u64, APIError> {
+        let url = format!("/r/{}/about/contributors?limit=100&raw_json=1", self.name);
+        let mut count = 0;
+        let mut after: Option<String> = None;
+        loop {
+            let page_url = match after {
+                Some(ref after) => format!("{}&after={}", url, after),
+                None => url.to_owned(),
+            };
+            let string = self.client.get_json(&page_url, false)?;
+            let json: listing::UserList = serde_json::from_str(&string)?;
+            count += json.data.dist.unwrap_or(json.data.children.len() as u64);
+            match json.data.after {
+                Some(next) => after = Some(next),
+                None => return Ok(count),
+            }
+        }
+    }
+
+    /// Gets the moderators of this subreddit.
+    pub fn moderators(&self) -> Result<UserListing<'_>, APIError> {
+        self.user_list("moderators")
+    }
+
+    /// Gets the users who are banned from this subreddit, with the note and date of each ban and
+    /// the days left of temporary bans. You must be a moderator with the 'access' permission to
+    /// do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::ListingOptions;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// for ban in sub.banned(ListingOptions::default()).expect("Request failed") {
+    ///     match ban.days_left {
+    ///         Some(days) => println!("{} ({} days left): {}", ban.name, days, ban.note),
+    ///         None => println!("{} (permanent): {}", ban.name, ban.note),
+    ///     }
+    /// }
+    /// ```
+    pub fn banned(&self, opts: ListingOptions) -> Result<BannedUserListing<'_>, APIError> {
+        self.banned_list("banned", opts)
+    }
+
+    /// Gets the users who are muted in this subreddit, and so cannot message its moderators. See
+    /// `Subreddit.banned()` for an example. You must be a moderator with the 'access'
+    /// permission to do this.
+    pub fn muted(&self, opts: ListingOptions) -> Result<BannedUserListing<'_>, APIError> {
+        self.banned_list("muted", opts)
+    }
+
+    fn banned_list(&self, ty: &str, opts: ListingOptions) -> Result<BannedUserListing<'_>, APIError> {
+        // As in `Subreddit.get_feed()`, the anchor is left out of the stem for pagination.
+        let url = format!("/r/{}/about/{}?limit={}&raw_json=1", self.name, ty, opts.batch);
+        let full_url = format!("{}&{}", url, opts.anchor);
+        let string = self.client.get_json(&full_url, false)?;
+        let json: listing::UserList = serde_json::from_str(&string)?;
+        Ok(BannedUserListing::new(UserListing::new(self.client, url, json.data)))
+    }
+
+    fn user_list(&self, ty: &str) -> Result<UserListing<'_>, APIError> {
+        let url = format!("/r/{}/about/{}?raw_json=1", self.name, ty);
+        let string = self.client.get_json(&url, false)?;
+        let json: listing::UserList = serde_json::from_str(&string)?;
+        Ok(UserListing::new(self.client, url, json.data))
+    }
+
+    /// Fetches the settings of this subreddit, as shown on the moderator settings page. You must
+    /// be a moderator with the 'config' permission to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// let mut settings = sub.settings().expect("Could not fetch settings");
+    /// settings.spoilers_enabled = true;
+    /// sub.update_settings(settings).expect("Could not update settings");
+    /// ```
+    pub fn settings(&self) -> Result<SubredditSettings, APIError> {
+        let url = format!("/r/{}/about/edit?raw_json=1", self.name);
+        let string = self.client.get_json(&url, false)?;
+        let string: listing::SubredditSettingsResponse = serde_json::from_str(&string)?;
+        Ok(string.data)
+    }
+
+    /// Replaces the settings of this subreddit. Reddit resets any setting that is not sent, so
+    /// always start from the result of `Subreddit.settings()` rather than creating the settings
+    /// from scratch. Every setting that was fetched is sent back, including the ones in
+    /// `SubredditSettings.other`. If Reddit refuses a setting, the reason is returned as an
+    /// `APIError::RedditError`.
+    pub fn update_settings(&self, settings: SubredditSettings) -> Result<(), APIError> {
+        let body = self.site_admin_body(settings)?;
+        self.client.post_api("/api/site_admin", &body, false).map(|_| ())
+    }
+
+    /// Formats settings fetched from `/about/edit` as the body of `/api/site_admin`.
+    pub(crate) fn site_admin_body(&self, settings: SubredditSettings) -> Result<String, APIError> {
+        let fields = match serde_json::to_value(settings)? {
+            Value::Object(fields) => fields,
+            _ => unreachable!("The settings are serialized as an object"),
+        };
+        let mut body = format!("api_type=json&name={}", self.name);
+        for (key, value) in fields {
+            // A few settings are returned under different names than they are sent with.
+            let key = match key.as_str() {
+                "subreddit_id" => "sr",
+                "subreddit_type" => "type",
+                "content_options" => "link_type",
+                "language" => "lang",
+                key => key,
+            };
+            let value = match value {
+                Value::String(value) => value,
+                Value::Bool(value) => value.to_string(),
+                Value::Number(value) => value.to_string(),
+                // Unset settings (e.g. no suggested sort) are left out, which keeps them unset.
+                _ => continue,
+            };
+            body.push_str(&format!("&{}={}", key, self.client.url_escape(value)));
+        }
+        Ok(body)
+    }
+
+    /// Replaces the sidebar text of this subreddit with the specified **Markdown**, keeping all
+    /// other settings unchanged. You must be a moderator with the 'config' permission to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// sub.update_sidebar("**Currently online:** 5 users").expect("Could not update sidebar");
+    /// ```
+    pub fn update_sidebar(&self, markdown: &str) -> Result<(), APIError> {
+        let mut settings = self.settings()?;
+        settings.description = markdown.to_owned();
+        self.update_settings(settings)
+    }
+
+    /// Fetches the wiki page with the specified name, e.g. `index` or `faq/posting`.
+    pub fn wiki_page(&self, name: &str) -> Result<WikiPage<'a>, APIError> {
+        WikiPage::fetch(self.client, &self.name, name)
+    }
+
+    /// Checks whether a wiki page exists in this subreddit, e.g. before reading an optional
+    /// configuration page. Only a HTTP 404 response means that the page does not exist; other
+    /// errors (such as 403 if the wiki is disabled or private) are returned as errors.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("rust");
+    /// if sub.wiki_page_exists("faq").expect("Request failed") {
+    ///     let faq = sub.wiki_page("faq").expect("Could not fetch the FAQ");
+    /// }
+    /// ```
+    pub fn wiki_page_exists(&self, name: &str) -> Result<bool, APIError> {
+        match self.wiki_page(name) {
+            Ok(_) => Ok(true),
+            Err(APIError::HTTPError(StatusCode::NOT_FOUND)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replaces the contents of a wiki page, creating it if it does not exist. The reason is
+    /// shown in the page history. You must be allowed to edit the page (see
+    /// `WikiPage.may_revise()`); if Reddit refuses the edit, the reason is returned as an
+    /// `APIError::RedditError` (e.g. with the code `WIKI_DISABLED` if the wiki is disabled).
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// sub.wiki_edit("faq", "# FAQ\n\nNothing here yet.", "Create FAQ").expect("Could not edit");
+    /// ```
+    pub fn wiki_edit(&self, page: &str, content: &str, reason: &str) -> Result<(), APIError> {
+        wiki::edit_page(self.client, &self.name, page, content, reason)
+    }
+
+    /// Gets the AutoModerator configuration (YAML) of this subreddit, which is stored in the
+    /// `config/automoderator` wiki page. You must be a moderator with the 'config' permission to
+    /// do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// let rules = sub.automod_config().expect("Could not fetch AutoModerator config");
+    /// sub.set_automod_config(&rules, "Re-deploying rules").expect("Could not update config");
+    /// ```
+    pub fn automod_config(&self) -> Result<String, APIError> {
+        let page = self.wiki_page("config/automoderator")?;
+        Ok(page.content_markdown().to_owned())
+    }
+
+    /// Replaces the AutoModerator configuration of this subreddit. The YAML is sent as-is, and
+    /// Reddit will reject the edit if it is not valid. The reason is shown in the page history.
+    pub fn set_automod_config(&self, yaml: &str, reason: &str) -> Result<(), APIError> {
+        self.wiki_edit("config/automoderator", yaml, reason)
+    }
+
+    /// Gets the collections of posts in this subreddit.
+    pub fn collections(&self) -> Result<Vec<Collection<'a>>, APIError> {
+        let about = self.about()?;
+        let url = format!("/api/v1/collections/subreddit_collections?sr_fullname={}", about.name());
+        let string = self.client.get_json(&url, false)?;
+        let collections: Vec<CollectionData> = serde_json::from_str(&string)?;
+        Ok(collections.into_iter().map(|data| Collection::new(self.client, data)).collect())
+    }
+
+    /// Creates a new collection of posts in this subreddit. This requires an OAuth
+    /// authenticator with the `modposts` scope. See `Collection` for an example.
+    pub fn create_collection(&self,
+                             title: &str,
+                             description: &str,
+                             display_layout: CollectionLayout)
+                             -> Result<Collection<'a>, APIError> {
+        let about = self.about()?;
+        let body = format!("sr_fullname={}&title={}&description={}&display_layout={}",
+                           about.name(),
+                           self.client.url_escape(title.to_owned()),
+                           self.client.url_escape(description.to_owned()),
+                           display_layout);
+        let string = self.client.post_json("/api/v1/collections/create_collection", &body, true)?;
+        let data: CollectionData = serde_json::from_str(&string)?;
+        Ok(Collection::new(self.client, data))
+    }
+
+    /// Creates a new flair template in this subreddit, returning the ID of the template. You
+    /// must be a moderator with the 'flair' permission to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{FlairTemplateSpec, FlairType};
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// let spec = FlairTemplateSpec::new("Solved", FlairType::Link).background_color("#46d160");
+    /// let id = sub.create_flair_template(spec).expect("Could not create flair template");
+    /// ```
+    pub fn create_flair_template(&self, spec: FlairTemplateSpec) -> Result<String, APIError> {
+        let body = self.flair_template_body(spec);
+        let url = format!("/r/{}/api/flairtemplate_v2", self.name);
+        let string = self.client.post_json(&url, &body, false)?;
+        let template: FlairTemplate = serde_json::from_str(&string)?;
+        Ok(template.id)
+    }
+
+    /// Replaces the settings of an existing flair template with the specified ones. Flairs that
+    /// have already been assigned are updated too. You must be a moderator with the 'flair'
+    /// permission to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{FlairTemplateSpec, FlairType};
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// let spec = FlairTemplateSpec::new("Answered", FlairType::Link).background_color("#0079d3");
+    /// sub.edit_flair_template("b8a1bc8a-1d6a-11e6-a1d4-0e5f4c9d5a5f", spec)
+    ///     .expect("Could not edit flair template");
+    /// ```
+    pub fn edit_flair_template(&self, template_id: &str, spec: FlairTemplateSpec)
+                               -> Result<(), APIError> {
+        if !is_template_id(template_id) {
+            return Err(APIError::InvalidTemplateId(template_id.to_owned()));
+        }
+        let body = format!("{}&flair_template_id={}", self.flair_template_body(spec), template_id);
+        let url = format!("/r/{}/api/flairtemplate_v2", self.name);
+        self.client.post_api(&url, &body, false).map(|_| ())
+    }
+
+    /// Formats the settings of a flair template for `/api/flairtemplate_v2`, which is used to
+    /// both create and edit templates.
+    fn flair_template_body(&self, spec: FlairTemplateSpec) -> String {
+        format!("api_type=json&flair_type={}&text={}&css_class={}&text_color={}\
+                 &background_color={}&mod_only={}&text_editable={}",
+                spec.flair_type,
+                self.client.url_escape(spec.text),
+                self.client.url_escape(spec.css_class.unwrap_or_default()),
+                spec.text_color.unwrap_or_else(|| String::from("dark")),
+                self.client.url_escape(spec.background_color.unwrap_or_default()),
+                spec.mod_only,
+                spec.text_editable)
+    }
+
+    /// Fetches the post flair templates of this subreddit. This requires an OAuth
+    /// authenticator.
+    pub fn post_flair_templates(&self) -> Result<Vec<FlairTemplate>, APIError> {
+        let url = format!("/r/{}/api/link_flair_v2?raw_json=1", self.name);
+        let string = self.client.get_json(&url, true)?;
+        Ok(serde_json::from_str(&string)?)
+    }
+
+    /// Looks up the post flair template with the specified ID, e.g. to check that a template
+    /// from a configuration file still exists. Returns `None` if there is no such template.
+    /// Reddit cannot look up a single template, so this fetches all of them with
+    /// `Subreddit.post_flair_templates()`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// match sub.get_post_flair("b8a1bc8a-1d6a-11e6-a1d4-0e5f4c9d5a5f").expect("Request failed") {
+    ///     Some(template) => println!("The flair is now called {}", template.text),
+    ///     None => println!("The flair has been deleted"),
+    /// }
+    /// ```
+    pub fn get_post_flair(&self, template_id: &str) -> Result<Option<FlairTemplate>, APIError> {
+        let templates = self.post_flair_templates()?;
+        Ok(templates.into_iter().find(|template| template.id == template_id))
+    }
+
+    /// Deletes the flair template with the specified ID. Flairs that have already been assigned
+    /// are not removed. If Reddit refuses to delete the template, `APIError::RedditError` is
+    /// returned.
+    pub fn delete_flair_template(&self, id: &str) -> Result<(), APIError> {
+        if !is_template_id(id) {
+            return Err(APIError::InvalidTemplateId(id.to_owned()));
         }
+        let body = format!("api_type=json&flair_template_id={}", id);
+        let url = format!("/r/{}/api/deleteflairtemplate", self.name);
+        self.client.post_api(&url, &body, false).map(|_| ())
     }
+
+    /// Sets the flair of a user in this subreddit directly from its text and CSS class, without
+    /// a flair template. This is the legacy flair system that some subreddits still use. You
+    /// must be a moderator with the 'flair' permission to do this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// sub.flair_user_legacy("Aurora0001", "Contributor", "contributor")
+    ///     .expect("Could not set flair");
+    /// ```
+    pub fn flair_user_legacy(&self, username: &str, text: &str, css_class: &str)
+                             -> Result<(), APIError> {
+        self.legacy_flair(&format!("name={}", username), text, css_class)
+    }
+
+    /// Sets the flair of a post in this subreddit directly from its text and CSS class, like
+    /// `Subreddit.flair_user_legacy()`. The post is given by its full name, e.g. `t3_4uule8`.
+    pub fn flair_post_legacy(&self, link_fullname: &str, text: &str, css_class: &str)
+                             -> Result<(), APIError> {
+        self.legacy_flair(&format!("link={}", link_fullname), text, css_class)
+    }
+
+    fn legacy_flair(&self, target: &str, text: &str, css_class: &str) -> Result<(), APIError> {
+        let body = format!("api_type=json&{}&text={}&css_class={}",
+                           target,
+                           self.client.url_escape(text.to_owned()),
+                           self.client.url_escape(css_class.to_owned()));
+        let url = format!("/r/{}/api/flair", self.name);
+        self.client.post_api(&url, &body, false).map(|_| ())
+    }
+
+    /// Opts into viewing this quarantined subreddit for the logged-in user, like clicking
+    /// through the warning on the website. Until this is done, the listings of a quarantined
+    /// subreddit are empty (see `SubredditAbout.is_quarantined()` and
+    /// `SubredditAbout.quarantine_message()`).
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("some_quarantined_subreddit");
+    /// if sub.about().expect("Could not fetch subreddit").is_quarantined() {
+    ///     sub.opt_into_quarantine().expect("Could not opt in");
+    /// }
+    /// ```
+    pub fn opt_into_quarantine(&self) -> Result<(), APIError> {
+        let body = format!("sr_name={}", self.name);
+        self.client.post_success("/api/quarantine_optin", &body, false)
+    }
+
+    /// Opts out of viewing this quarantined subreddit again, so its listings are empty for the
+    /// logged-in user.
+    pub fn opt_out_of_quarantine(&self) -> Result<(), APIError> {
+        let body = format!("sr_name={}", self.name);
+        self.client.post_success("/api/quarantine_optout", &body, false)
+    }
+
     /// Subscribes to the specified subredit, returning the result to show whether the API call
     /// succeeded or not.
     pub fn subscribe(&self) -> Result<(), APIError> {
@@ -250,11 +1231,142 @@ impl<'a> Subreddit<'a> {
         let body = format!("action=unsub&sr_name={}", self.name);
         self.client.post_success("/api/subscribe", &body, false)
     }
+
+    /// Subscribes to or unsubscribes from this subreddit. Reddit ignores subscribing twice or
+    /// unsubscribing when not subscribed, so this can be used to ensure a subscription state
+    /// without checking the current one first.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let feeds = [("rust", true), ("programming", false)];
+    /// for &(name, subscribed) in feeds.iter() {
+    ///     client.subreddit(name).set_subscribed(subscribed).expect("Could not update");
+    /// }
+    /// ```
+    pub fn set_subscribed(&self, subscribed: bool) -> Result<(), APIError> {
+        if subscribed {
+            self.subscribe()
+        } else {
+            self.unsubscribe()
+        }
+    }
+
+    /// Checks whether the logged-in user is subscribed to this subreddit. This is always `false`
+    /// when browsing anonymously. See `Subreddit.my_relationship()`.
+    pub fn is_subscribed(&self) -> Result<bool, APIError> {
+        Ok(self.my_relationship()?.subscriber)
+    }
+
+    /// Unsubscribes from this subreddit if the logged-in user is subscribed, and subscribes
+    /// otherwise. Returns whether the user is now subscribed.
+    pub fn toggle_subscription(&self) -> Result<bool, APIError> {
+        let subscribed = !self.is_subscribed()?;
+        self.set_subscribed(subscribed)?;
+        Ok(subscribed)
+    }
+
+    /// Fetches the relationship of the logged-in user with this subreddit, e.g. to check that
+    /// a bot has not been banned before posting. When browsing anonymously, every field of the
+    /// `Relationship` is `false`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let sub = client.subreddit("new_rawr");
+    /// if !sub.my_relationship().expect("Could not fetch relationship").banned {
+    ///     // Submit the post...
+    /// }
+    /// ```
+    pub fn my_relationship(&self) -> Result<Relationship, APIError> {
+        Ok(self.about()?.relationship())
+    }
+
+    /// Fetches the short description of this subreddit, as shown in search results, in
+    /// **Markdown** format. This may be empty.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let sub = client.subreddit("rust");
+    /// println!("{}", sub.public_description().expect("Could not fetch description"));
+    /// ```
+    pub fn public_description(&self) -> Result<String, APIError> {
+        Ok(self.about()?.data.public_description)
+    }
+
+    /// Fetches the full sidebar text of this subreddit, which often contains its rules and FAQ,
+    /// in **Markdown** format. Use `Subreddit.about()` to get both descriptions with one request.
+    pub fn long_description_markdown(&self) -> Result<String, APIError> {
+        Ok(self.about()?.data.description)
+    }
+
+    /// Counts the items waiting in the moderation queues of this subreddit, e.g. to show badges
+    /// on a dashboard. Reddit has no endpoint for the counts, so the first page of each queue is
+    /// fetched (all four at once), and each count is capped at 100 - like Reddit's own badges,
+    /// treat 100 as "100 or more". You must be a moderator with the 'posts' permission to do
+    /// this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let counts = client.subreddit("new_rawr").mod_queue_count().expect("Request failed");
+    /// println!("{} reported, {} unmoderated", counts.reports, counts.unmoderated);
+    /// ```
+    pub fn mod_queue_count(&self) -> Result<ModQueueCounts, APIError> {
+        let queues = ["modqueue", "spam", "reports", "unmoderated"];
+        let counts = self.client.batch(&queues, queues.len(), |queue| {
+            let url = format!("/r/{}/about/{}?limit=100&raw_json=1", self.name, queue);
+            let string = self.client.get_json(&url, false)?;
+            let listing: BasicThing<ListingData<Value>> = serde_json::from_str(&string)?;
+            Ok(listing.data.children.len() as u64)
+        });
+        let mut counts = counts.into_iter();
+        let mut next = || counts.next().expect("One count per queue");
+        Ok(ModQueueCounts {
+            modqueue: next()?,
+            spam: next()?,
+            reports: next()?,
+            unmoderated: next()?,
+        })
+    }
+}
+
+/// The relationship of the logged-in user with a subreddit. See `Subreddit.my_relationship()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Relationship {
+    /// True if the user is banned from the subreddit.
+    pub banned: bool,
+    /// True if the user is subscribed to the subreddit.
+    pub subscriber: bool,
+    /// True if the user is a moderator of the subreddit.
+    pub moderator: bool,
+    /// True if the user is an approved contributor of the subreddit.
+    pub contributor: bool,
+}
+
+/// The number of items in each moderation queue of a subreddit, capped at 100. See
+/// `Subreddit.mod_queue_count()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModQueueCounts {
+    /// Posts and comments that were reported or removed by the spam filter.
+    pub modqueue: u64,
+    /// Posts and comments that were removed as spam.
+    pub spam: u64,
+    /// Posts and comments that were reported.
+    pub reports: u64,
+    /// Posts that no moderator has approved or removed yet.
+    pub unmoderated: u64,
 }
 
 /// Information about a subreddit such as subscribers, sidebar text and active users.
 pub struct SubredditAbout {
-    data: listing::SubredditAboutData,
+    ///About data for the subreddit
+    pub data: listing::SubredditAboutData,
 }
 
 impl Created for SubredditAbout {
@@ -274,6 +1386,17 @@ impl SubredditAbout {
         SubredditAbout { data: data }
     }
 
+    /// The relationship of the logged-in user with the subreddit, when the information was
+    /// fetched. Every field is `false` if it was fetched anonymously.
+    pub fn relationship(&self) -> Relationship {
+        Relationship {
+            banned: self.data.user_is_banned.unwrap_or(false),
+            subscriber: self.data.user_is_subscriber.unwrap_or(false),
+            moderator: self.data.user_is_moderator.unwrap_or(false),
+            contributor: self.data.user_is_contributor.unwrap_or(false),
+        }
+    }
+
     /// The number of subscribers to this subreddit.
     pub fn subscribers(&self) -> u64 {
         self.data.subscribers
@@ -285,6 +1408,12 @@ impl SubredditAbout {
         self.data.accounts_active
     }
 
+    /// Returns `true` if Reddit has randomized the number of active users slightly, which it
+    /// does when there are only a few active users.
+    pub fn active_users_is_fuzzed(&self) -> bool {
+        self.data.accounts_active_is_fuzzed.unwrap_or(false)
+    }
+
     /// Returns `true` if the subreddit is visible to the public (i.e. not invitation only)
     pub fn public(&self) -> bool {
         self.data.public_traffic
@@ -294,4 +1423,57 @@ impl SubredditAbout {
     pub fn display_name(&self) -> &str {
         &self.data.display_name
     }
+
+    /// The full name of the subreddit, e.g. `t5_2qh1i`.
+    pub fn name(&self) -> &str {
+        &self.data.name
+    }
+
+    /// Returns `true` if the subreddit has been quarantined by Reddit. Listings of quarantined
+    /// subreddits are empty until the quarantine is acknowledged with
+    /// `Subreddit.opt_into_quarantine()`.
+    pub fn is_quarantined(&self) -> bool {
+        self.data.quarantine
+    }
+
+    /// The warning that Reddit shows before the content of a quarantined subreddit, in
+    /// **Markdown** format, if the subreddit is quarantined.
+    pub fn quarantine_message(&self) -> Option<&str> {
+        self.data.quarantine_message.as_deref()
+    }
+
+    /// The quarantine warning in **HTML** format, if the subreddit is quarantined.
+    pub fn quarantine_message_html(&self) -> Option<&str> {
+        self.data.quarantine_message_html.as_deref()
+    }
+
+    /// The sidebar text of the subreddit, in **Markdown** format.
+    pub fn sidebar_markdown(&self) -> &str {
+        &self.data.description
+    }
+
+    /// The short description of the subreddit, as shown in search results, in **Markdown**
+    /// format.
+    pub fn public_description(&self) -> &str {
+        &self.data.public_description
+    }
+
+    /// The short description of the subreddit, rendered as HTML.
+    pub fn public_description_html(&self) -> &str {
+        &self.data.public_description_html
+    }
+
+    /// The sidebar text of the subreddit, rendered as HTML.
+    pub fn sidebar_html(&self) -> &str {
+        &self.data.description_html
+    }
+}
+
+/// Checks that an ID has the format of a flair template ID, which is a UUID such as
+/// `b8a1bc8a-1d6a-11e6-a1d4-0e5f4c9d5a5f`.
+fn is_template_id(id: &str) -> bool {
+    id.len() == 36 && id.char_indices().all(|(i, c)| match i {
+        8 | 13 | 18 | 23 => c == '-',
+        _ => c.is_ascii_hexdigit(),
+    })
 }
@@ -1,14 +1,17 @@
 use crate::structures::submission::FlairList;
-use crate::structures::listing::Listing;
+use crate::structures::listing::{Listing, MixedListing};
 use crate::client::RedditClient;
-use crate::responses::{FlairSelectorResponse, listing};
+use crate::responses::{BasicThing, FlairSelectorResponse, listing};
 use crate::responses::user::{UserAbout as _UserAbout, UserAboutData, UserAboutDataCore};
 use crate::responses::listing::{Listing as _Listing, UserListingData};
 use crate::traits::{Created, PageListing};
 use crate::errors::APIError;
+use crate::options::{ListingOptions, UserContentSort, UserSubmissionsOptions};
 use crate::structures::comment_list::CommentList;
 use crate::responses::comment::CommentListing;
 use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// Interface to a Reddit user, which can be used to access their karma and moderator status.
 pub struct User<'a> {
@@ -17,6 +20,27 @@ pub struct User<'a> {
     pub name: String,
 }
 
+// Reddit treats usernames case-insensitively, so they are compared and hashed that way too.
+impl<'a> PartialEq for User<'a> {
+    fn eq(&self, other: &User) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl<'a> Eq for User<'a> {}
+
+impl<'a> Hash for User<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.to_ascii_lowercase().hash(state);
+    }
+}
+
+impl<'a> fmt::Debug for User<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("User").field("name", &self.name).finish()
+    }
+}
+
 impl<'a> User<'a> {
     /// Internal method. Use `RedditClient.user(NAME)` instead.
     pub fn new(client: &'a RedditClient, name: &str) -> User<'a> {
@@ -65,6 +89,13 @@ impl<'a> User<'a> {
         self.client.post_success(&url, &body, false)
     }
 
+    /// Blocks this user, so that they can no longer send private messages to the logged-in
+    /// account. This requires the `privatemessages` OAuth scope.
+    pub fn block(&self) -> Result<(), APIError> {
+        let body = format!("name={}", self.name);
+        self.client.post_success("/api/block_user", &body, false)
+    }
+
     /// Gets a list of *submissions* that the specified user has submitted. This endpoint is a
     /// listing and will continue yielding items until every item has been exhausted.
     /// # Examples
@@ -83,12 +114,71 @@ impl<'a> User<'a> {
     /// ```
     pub fn submissions(&self) -> Result<Listing, APIError> {
         let url = format!("/user/{}/submitted?raw_json=1", self.name);
-        let result = self.client
-            .get_json(&url, false).unwrap();
-        let result: _Listing = serde_json::from_str(&*result).unwrap();
+        let result = self.client.get_json(&url, false)?;
+        let result: _Listing = serde_json::from_str(&result)?;
+        Ok(Listing::new(self.client, url, result.data))
+    }
+
+    /// Gets a list of the submissions of this user like `User.submissions()`, but in the
+    /// specified order and with the specified batch size and anchor.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{ListingOptions, UserContentSort, UserSubmissionsOptions};
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let user = client.user("Aurora0001");
+    /// let best = user.submissions_sorted(ListingOptions::default(), UserContentSort::Top)
+    ///     .expect("Could not fetch!");
+    /// for post in best.take(5) {
+    ///     println!("{}", post.title());
+    /// }
+    /// ```
+    pub fn submissions_sorted(&self, opts: ListingOptions, sort: UserContentSort)
+                              -> Result<Listing<'a>, APIError> {
+        self.submissions_with_options(UserSubmissionsOptions::default().sort(sort).listing(opts))
+    }
+
+    /// Gets a list of the submissions of this user with the order, time span and pagination
+    /// given in `opts`, e.g. to find the best posts of a user in the past year.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::options::{TimeFilter, UserContentSort, UserSubmissionsOptions};
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let user = client.user("Aurora0001");
+    /// let opts = UserSubmissionsOptions::default()
+    ///     .sort(UserContentSort::Top)
+    ///     .time(TimeFilter::Year);
+    /// for post in user.submissions_with_options(opts).expect("Could not fetch!").take(5) {
+    ///     println!("{}", post.title());
+    /// }
+    /// ```
+    pub fn submissions_with_options(&self, opts: UserSubmissionsOptions)
+                                    -> Result<Listing<'a>, APIError> {
+        let time = opts.time.map(|time| time.to_string()).unwrap_or_default();
+        // The anchor is left out of the stem so the pagination can adjust it later on.
+        let url = format!("/user/{}/submitted?{}{}&limit={}&raw_json=1",
+                          self.name, opts.sort, time, opts.listing.batch);
+        let full_url = format!("{}&{}", url, opts.listing.anchor);
+        let result = self.client.get_json(&full_url, false)?;
+        let result: _Listing = serde_json::from_str(&result)?;
         Ok(Listing::new(self.client, url, result.data))
     }
-    // TODO: implement comment, overview, gilded listings etc.
+
+    /// Gets a list of both the submissions and comments of this user, newest first. This
+    /// endpoint is a listing and will continue yielding items until every item has been
+    /// exhausted.
+    pub fn overview(&self) -> Result<MixedListing<'a>, APIError> {
+        let url = format!("/user/{}/overview?raw_json=1", self.name);
+        let result = self.client.get_json(&url, false)?;
+        let result: BasicThing<listing::ListingData<serde_json::Value>> =
+            serde_json::from_str(&result)?;
+        Ok(MixedListing::new(self.client, url, result.data))
+    }
+
+    // TODO: implement gilded listings etc.
     ///Incomplete get comments
     pub fn comments(&self) -> Result<CommentListing, APIError> {
         let url = format!("/user/{}/comments?raw_json=1", self.name);
@@ -110,18 +200,9 @@ impl UserAbout {
     /// Internal method. Use `RedditClient.user(NAME).about()` instead.
     pub fn new(client: &RedditClient, name: String) -> Result<UserAbout, APIError> {
         let url = format!("/user/{}/about?raw_json=1", name);
-        let result1 = client.get_json(&url, false);
-        if result1.is_err() {
-            return Err(result1.err().unwrap());
-        }
-        let result = result1.unwrap();
-        let result: Result<UserAboutDataCore, serde_json::Error> = serde_json::from_str(&*result);
-        if result.is_err() {
-            return Err(APIError::JSONError(result.err().unwrap()));
-        }
-        Ok(UserAbout {
-            data: result.unwrap().data
-        })
+        let result = client.get_json(&url, false)?;
+        let result: UserAboutDataCore = serde_json::from_str(&result)?;
+        Ok(UserAbout { data: result.data })
     }
 
     /// Gets the user's link karma (including self post karma as of July 19th, 2016).
@@ -151,6 +232,7 @@ impl Created for UserAbout {
 }
 
 
+/// A paginated listing of users, such as the approved contributors of a subreddit.
 pub struct UserListing<'a> {
     client: &'a RedditClient,
     query_stem: String,
@@ -190,19 +272,16 @@ impl<'a> UserListing<'a> {
         match self.after() {
             Some(after_id) => {
                 let url = format!("{}&after={}", self.query_stem, after_id);
-                let string = self.client
-                    .get_json(&url, false).unwrap();
-                let string: listing::UserListing = serde_json::from_str(&*string).unwrap();
-                Ok(UserListing::new(self.client, self.query_stem.to_owned(), string))
+                let string = self.client.get_json(&url, false)?;
+                let string: listing::UserList = serde_json::from_str(&string)?;
+                Ok(UserListing::new(self.client, self.query_stem.to_owned(), string.data))
             }
             None => Err(APIError::ExhaustedListing),
         }
     }
-}
 
-impl<'a> Iterator for UserListing<'a> {
-    type Item = User<'a>;
-    fn next(&mut self) -> Option<User<'a>> {
+    /// Yields the next entry of the listing, fetching the next page when necessary.
+    fn next_entry(&mut self) -> Option<UserListingData> {
         if self.data.children.is_empty() {
             if self.after().is_none() {
                 None
@@ -210,11 +289,57 @@ impl<'a> Iterator for UserListing<'a> {
                 let mut new_listing = self.fetch_after().expect("After does not exist!");
                 self.data.children.append(&mut new_listing.data.children);
                 self.data.after = new_listing.data.after;
-                self.next()
+                self.next_entry()
             }
         } else {
-            let child = self.data.children.drain(..1).next().unwrap();
-            Some(User::new(self.client, child.name.as_str()))
+            Some(self.data.children.remove(0))
         }
     }
 }
+
+impl<'a> Iterator for UserListing<'a> {
+    type Item = User<'a>;
+    fn next(&mut self) -> Option<User<'a>> {
+        self.next_entry().map(|child| User::new(self.client, child.name.as_str()))
+    }
+}
+
+/// A user in the list of banned or muted users of a subreddit.
+#[derive(Debug, Clone)]
+pub struct BannedUser {
+    /// The name of the user.
+    pub name: String,
+    /// The moderator's note on the ban or mute, which may be empty.
+    pub note: String,
+    /// The UTC timestamp of when the user was banned or muted.
+    pub date: f64,
+    /// The number of days until a temporary ban ends, or `None` for permanent bans and mutes.
+    pub days_left: Option<u64>,
+}
+
+/// A paginated listing of the banned or muted users of a subreddit, which yields the details of
+/// each ban rather than just the users. See `Subreddit.banned()` and `Subreddit.muted()`.
+pub struct BannedUserListing<'a> {
+    users: UserListing<'a>,
+}
+
+impl<'a> BannedUserListing<'a> {
+    /// Internal method. Use `Subreddit.banned()` or `Subreddit.muted()` instead.
+    pub fn new(users: UserListing<'a>) -> BannedUserListing<'a> {
+        BannedUserListing { users }
+    }
+}
+
+impl<'a> Iterator for BannedUserListing<'a> {
+    type Item = BannedUser;
+    fn next(&mut self) -> Option<BannedUser> {
+        self.users.next_entry().map(|child| {
+            BannedUser {
+                name: child.name,
+                note: child.note.unwrap_or_default(),
+                date: child.date,
+                days_left: child.days_left,
+            }
+        })
+    }
+}
@@ -0,0 +1,189 @@
+use hyper::{Body, StatusCode};
+use serde_json::Value;
+
+use crate::client::RedditClient;
+use crate::errors::{APIError, RedditError};
+use crate::responses::wiki::{WikiPageData, WikiPageResponse, WikiPageSettingsResponse};
+
+/// A page of a subreddit's wiki, such as the index page or the AutoModerator configuration.
+/// # Examples
+/// ```rust,no_run
+/// use new_rawr::client::RedditClient;
+/// use new_rawr::auth::AnonymousAuthenticator;
+/// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+/// let page = client.subreddit("rust").wiki_page("index").expect("Could not fetch wiki page");
+/// println!("{}", page.content_markdown());
+/// ```
+pub struct WikiPage<'a> {
+    client: &'a RedditClient,
+    subreddit: String,
+    name: String,
+    data: WikiPageData,
+}
+
+impl<'a> WikiPage<'a> {
+    /// Internal method. Use `Subreddit.wiki_page(NAME)` instead.
+    pub fn fetch(client: &'a RedditClient, subreddit: &str, name: &str)
+                 -> Result<WikiPage<'a>, APIError> {
+        let url = format!("/r/{}/wiki/{}?raw_json=1", subreddit, escape_page_name(client, name));
+        let string = client.get_json(&url, false)?;
+        let string: WikiPageResponse = serde_json::from_str(&string)?;
+        Ok(WikiPage {
+            client,
+            subreddit: subreddit.to_owned(),
+            name: name.to_owned(),
+            data: string.data,
+        })
+    }
+
+    /// The name of this page, e.g. `index` or `config/automoderator`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of the subreddit this page belongs to (not including `/r/`).
+    pub fn subreddit(&self) -> &str {
+        &self.subreddit
+    }
+
+    /// The page text in **Markdown** format.
+    pub fn content_markdown(&self) -> &str {
+        &self.data.content_md
+    }
+
+    /// The page text rendered as HTML.
+    pub fn content_html(&self) -> &str {
+        &self.data.content_html
+    }
+
+    /// `true` if the logged-in user is allowed to edit this page.
+    pub fn may_revise(&self) -> bool {
+        self.data.may_revise
+    }
+
+    /// The UTC timestamp of the latest revision, if available.
+    pub fn revision_date(&self) -> Option<i64> {
+        self.data.revision_date.map(|date| date as i64)
+    }
+
+    /// Fetches who may edit this page. You must be a moderator with the 'wiki' permission to do
+    /// this.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::auth::PasswordAuthenticator;
+    /// use new_rawr::client::RedditClient;
+    /// let client = RedditClient::new("new_rawr", PasswordAuthenticator::new("a", "b", "c", "d"));
+    /// let page = client.subreddit("new_rawr").wiki_page("faq").expect("Could not fetch page");
+    /// let permissions = page.permissions().expect("Could not fetch permissions");
+    /// if permissions.permlevel != 2 {
+    ///     page.set_permissions(2).expect("Could not restrict page to moderators");
+    /// }
+    /// ```
+    pub fn permissions(&self) -> Result<WikiPermissions, APIError> {
+        let url = format!("/r/{}/wiki/settings/{}?raw_json=1",
+                          self.subreddit,
+                          escape_page_name(self.client, &self.name));
+        let string = self.client.get_json(&url, false)?;
+        let string: WikiPageSettingsResponse = serde_json::from_str(&string)?;
+        Ok(WikiPermissions {
+            permlevel: string.data.permlevel,
+            editors: string.data.editors.into_iter().map(|editor| editor.data.name).collect(),
+            listed: string.data.listed,
+        })
+    }
+
+    /// Changes who may edit this page (see `WikiPermissions.permlevel`). Whether the page is
+    /// listed is not changed.
+    pub fn set_permissions(&self, permlevel: u8) -> Result<(), APIError> {
+        let listed = self.permissions()?.listed;
+        let body = format!("page={}&permlevel={}&listed={}",
+                           self.client.url_escape(self.name.to_owned()),
+                           permlevel,
+                           listed);
+        let url = format!("/r/{}/wiki/settings/{}",
+                          self.subreddit,
+                          escape_page_name(self.client, &self.name));
+        self.client.post_success(&url, &body, false)
+    }
+
+    /// Allows a user to edit this page, even if they could not otherwise.
+    pub fn add_editor(&self, username: &str) -> Result<(), APIError> {
+        self.change_editor("add", username)
+    }
+
+    /// Removes a user from the editors of this page that were added with
+    /// `WikiPage.add_editor()`.
+    pub fn remove_editor(&self, username: &str) -> Result<(), APIError> {
+        self.change_editor("del", username)
+    }
+
+    fn change_editor(&self, action: &str, username: &str) -> Result<(), APIError> {
+        let body = format!("page={}&username={}",
+                           self.client.url_escape(self.name.to_owned()),
+                           username);
+        let url = format!("/r/{}/api/wiki/alloweditor/{}", self.subreddit, action);
+        self.client.post_success(&url, &body, false)
+    }
+}
+
+/// Who may edit a wiki page. See `WikiPage.permissions()`.
+#[derive(Debug)]
+pub struct WikiPermissions {
+    /// Who may edit the page: 0 = anyone allowed by the subreddit wiki settings, 1 = only
+    /// approved wiki contributors, 2 = only moderators.
+    pub permlevel: u8,
+    /// The users who have been allowed to edit this page specifically.
+    pub editors: Vec<String>,
+    /// `false` if the page is hidden from the list of wiki pages.
+    pub listed: bool,
+}
+
+/// URL encodes each part of a wiki page name, keeping the `/` separators of nested pages.
+pub fn escape_page_name(client: &RedditClient, name: &str) -> String {
+    name.split('/')
+        .map(|part| client.url_escape(part.to_owned()))
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+/// Replaces the contents of a wiki page, creating it if it does not exist. Use
+/// `Subreddit.wiki_edit()` or `Subreddit.set_automod_config()` instead.
+///
+/// If Reddit refuses the edit, the reason it gives (e.g. `WIKI_DISABLED`, `PAGE_NOT_CREATED`
+/// or `EDIT_CONFLICT`) is returned as an `APIError::RedditError`. Otherwise a failed request
+/// gives an `APIError::HTTPError`.
+pub fn edit_page(client: &RedditClient,
+                 subreddit: &str,
+                 name: &str,
+                 content: &str,
+                 reason: &str)
+                 -> Result<(), APIError> {
+    let body = format!("page={}&content={}&reason={}",
+                       client.url_escape(name.to_owned()),
+                       client.url_escape(content.to_owned()),
+                       client.url_escape(reason.to_owned()));
+    let url = format!("/r/{}/api/wiki/edit", subreddit);
+    client.ensure_authenticated(|| {
+        let request = client.post(&url, false)?.body(Body::from(body.to_owned())).unwrap();
+        let runtime = tokio::runtime::Runtime::new().expect("Unable to create a runtime");
+        let response = runtime.block_on(client.send(request))?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        // The wiki endpoints report errors as {"reason": ..., "message": ...} along with the
+        // error status, rather than in a json.errors array.
+        let response = runtime.block_on(hyper::body::to_bytes(response.into_body()))?;
+        let response: Value = serde_json::from_slice(&response).unwrap_or(Value::Null);
+        match response["reason"].as_str() {
+            Some(code) if status != StatusCode::UNAUTHORIZED => {
+                Err(APIError::RedditError(RedditError {
+                    code: code.to_owned(),
+                    message: response["message"].as_str().unwrap_or_default().to_owned(),
+                    field: None,
+                }))
+            }
+            _ => Err(APIError::HTTPError(status)),
+        }
+    })
+}
@@ -14,6 +14,12 @@ use crate::structures::comment::Comment;
 pub trait Votable {
     /// The (fuzzed) points score of the object.
     fn score(&self) -> i64;
+    /// The score of the object as it is shown on Reddit, or `None` if the score is currently
+    /// hidden (e.g. new comments in subreddits that hide comment scores). Prefer this to
+    /// `score()` when displaying scores, since hidden scores are meaningless.
+    fn displayed_score(&self) -> Option<i64> {
+        Some(self.score())
+    }
     /// Indicates the logged-in user's current vote on this object:
     /// - Some(true) = Upvoted
     /// - Some(false) = Downvoted
@@ -65,8 +71,13 @@ pub trait Editable {
 
 /// An object that was created by an author and is in a subreddit (i.e. a submission or comment)
 pub trait Content {
-    /// The author of the object.
+    /// The author of the object. If the author has deleted their account (or the content was
+    /// removed), this is a `User` named `[deleted]` (or `[removed]`), which cannot be fetched;
+    /// check `author_name()` first to avoid such requests.
     fn author(&self) -> User;
+    /// The name of the author of the object, or `None` if the author's account has been
+    /// deleted or the content was removed.
+    fn author_name(&self) -> Option<&str>;
     /// The flair text of the user flair, if present.
     fn author_flair_text(&self) -> Option<String>;
     /// The flair CSS class of the user flair, if present.
@@ -75,6 +86,8 @@ pub trait Content {
     /// this is the subreddit of the parent submission.
     fn subreddit(&self) -> Subreddit;
     /// Deletes the specified object, if possible. **This may be irreversible. Use with caution.**
+    /// If Reddit refuses to delete the object (e.g. it was posted by someone else),
+    /// `APIError::RedditError` is returned.
     fn delete(self) -> Result<(), APIError>;
     /// Gets the full ID of this comment (kind + id)
     fn name(&self) -> &str;
@@ -99,7 +112,8 @@ pub trait Approvable {
 pub trait Commentable<'a> {
     /// The number of comments on this object. Prefer this to `replies().count()`.
     fn reply_count(&self) -> u64;
-    /// Sends a reply with the specified body.
+    /// Sends a reply with the specified body. If the object has been archived, this fails with
+    /// `APIError::Archived` without sending a request.
     fn reply(&self, comment: &str) -> Result<Comment, APIError>;
     /// Gets all replies as a self-paginating `CommentList`, which can be iterated through as
     /// necessary. Comments cannot be batched like submission listings, so there may be
@@ -156,7 +170,12 @@ pub trait Reportable {
     /// Gets the number of reports for this post. If you do not have the rights to view this value,
     /// this will return `None`.
     fn report_count(&self) -> Option<u64>;
-    // TODO: add report_reasons
+    /// Gets the reports made by moderators as `(reason, moderator name)` pairs. This is empty if
+    /// there are no reports or you do not have the rights to view them.
+    fn mod_reports(&self) -> Vec<(String, String)>;
+    /// Gets the reports made by users as `(reason, number of reports)` pairs. This is empty if
+    /// there are no reports or you do not have the rights to view them.
+    fn user_reports(&self) -> Vec<(String, u64)>;
 }
 
 /// An object that has a flair and can be given a flair by the creator or a moderator.
@@ -195,6 +214,20 @@ pub trait Visible {
     }
 }
 
+/// The kinds of distinguish that can be shown next to a user's name. See
+/// `Distinguishable.distinguished_kind()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Distinguished {
+    /// A moderator of the subreddit: [M]
+    Moderator,
+    /// A Reddit admin: [A]
+    Admin,
+    /// Other special distinguishes, e.g. [Δ]
+    Special,
+    /// A kind of distinguish that new_rawr does not know about yet.
+    Other(String),
+}
+
 /// An object that can be distinguished (moderator/admin/special indicator).
 pub trait Distinguishable {
     /// Indicates whether the user has used a special flag for themselves, e.g. [M] or [A].
@@ -204,7 +237,30 @@ pub trait Distinguishable {
     /// - `Some("moderator")` - [M]
     /// - `Some("admin")` - [A]
     /// - `Some("special")` - other special 'distinguishes' e.g. [Δ]
+    ///
+    /// Prefer `distinguished_kind()`, which parses these values.
     fn distinguished(&self) -> Option<String>;
+    /// Gets the distinguish of the object as a `Distinguished` value, or `None` for a normal
+    /// user.
+    /// # Examples
+    /// ```rust,no_run
+    /// use new_rawr::client::RedditClient;
+    /// use new_rawr::auth::AnonymousAuthenticator;
+    /// use new_rawr::traits::{Distinguishable, Distinguished};
+    /// let client = RedditClient::new("new_rawr", AnonymousAuthenticator::new());
+    /// let post = client.get_by_id("t3_4uule8").unwrap().get().unwrap();
+    /// if post.distinguished_kind() == Some(Distinguished::Moderator) {
+    ///     println!("Posted as a moderator");
+    /// }
+    /// ```
+    fn distinguished_kind(&self) -> Option<Distinguished> {
+        self.distinguished().map(|kind| match kind.as_str() {
+            "moderator" => Distinguished::Moderator,
+            "admin" => Distinguished::Admin,
+            "special" => Distinguished::Special,
+            _ => Distinguished::Other(kind),
+        })
+    }
     /// Sets the post to have a [M] distinguish.
     fn distinguish(&mut self) -> Result<(), APIError>;
     /// Removes any distinguish on the comment. This will also unsticky a comment, if it is